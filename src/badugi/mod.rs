@@ -0,0 +1,142 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::{
+    naive_evaluator::card::{Card, Rank},
+    lowball::ace_to_five::ace_low_value
+};
+
+pub const BADUGI_HAND_SIZE: usize = 4;
+
+/// Badugi hand ranking. Hands with more cards of distinct ranks and suits are better,
+/// then lower cards are better with Ace counting as one. Better hands compare greater.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BadugiRanking {
+    /// Ranks of the playing cards from high to low counting Ace as one, only first `count` are used
+    ranks: [Rank; BADUGI_HAND_SIZE],
+    count: usize
+}
+
+impl BadugiRanking {
+    /// Ranks of the playing cards from high to low counting Ace as one
+    #[inline]
+    pub fn ranks(&self) -> &[Rank] {
+        return &self.ranks[..self.count];
+    }
+
+    /// Number of playing cards, 4 is a badugi
+    #[inline]
+    pub const fn count(&self) -> usize {
+        return self.count;
+    }
+
+    #[inline]
+    pub const fn is_badugi(&self) -> bool {
+        return self.count == BADUGI_HAND_SIZE;
+    }
+}
+
+impl Ord for BadugiRanking {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lowness = |ranking: &BadugiRanking| -> Vec<u8> {
+            return ranking.ranks().iter().map(|rank| ace_low_value(*rank)).collect();
+        };
+
+        return self.count.cmp(&other.count).then_with(|| lowness(other).cmp(&lowness(self)));
+    }
+}
+
+impl PartialOrd for BadugiRanking {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Display for BadugiRanking {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ranks: Vec<_> = self.ranks().iter().map(|rank| rank.to_string()).collect();
+        return write!(f, "{}", ranks.join("-"));
+    }
+}
+
+/// Best badugi out of four cards
+pub fn evaluate_badugi(cards: &[Card; BADUGI_HAND_SIZE]) -> BadugiRanking {
+    return (1u8..1 << BADUGI_HAND_SIZE)
+        .filter_map(|mask| {
+            let picked: Vec<_> = cards.iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, card)| *card)
+                .collect();
+
+            let distinct = picked.iter().enumerate().all(|(i, card)| {
+                return picked[i + 1..].iter().all(|other| other.rank() != card.rank() && other.suit() != card.suit());
+            });
+
+            if !distinct {
+                return None;
+            }
+
+            let mut ranks = [Rank::Ace; BADUGI_HAND_SIZE];
+
+            for (rank, card) in ranks.iter_mut().zip(&picked) {
+                *rank = card.rank();
+            }
+
+            ranks[..picked.len()].sort_unstable_by_key(|rank| std::cmp::Reverse(ace_low_value(*rank)));
+            return Some(BadugiRanking { ranks, count: picked.len() });
+        })
+        .max()
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::card::{Card, Rank};
+
+    use super::evaluate_badugi;
+
+    fn cards(value: &str) -> [Card; 4] {
+        return [0, 2, 4, 6].map(|i| Card::try_from(&value[i..i + 2]).unwrap());
+    }
+
+    #[test]
+    fn test_best_badugi() {
+        let nuts = evaluate_badugi(&cards("4s3h2dAc"));
+        assert!(nuts.is_badugi());
+        assert_eq!(nuts.ranks(), &[Rank::Four, Rank::Three, Rank::Two, Rank::Ace]);
+        assert_eq!(nuts.to_string(), "4-3-2-A");
+
+        assert!(nuts > evaluate_badugi(&cards("5s3h2dAc")));
+        assert!(evaluate_badugi(&cards("KsQhJdTc")) > evaluate_badugi(&cards("3s2hAd2c")));
+    }
+
+    #[test]
+    fn test_compares_from_highest_card() {
+        assert!(evaluate_badugi(&cards("8s5h3dAc")) > evaluate_badugi(&cards("8s6h2dAc")));
+        assert_eq!(
+            evaluate_badugi(&cards("7s5h3dAc")).cmp(&evaluate_badugi(&cards("7h5d3cAs"))),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_duplicate_suits_and_ranks() {
+        // Two spades, the lower one plays
+        let three = evaluate_badugi(&cards("Ks2s3h4d"));
+        assert_eq!(three.count(), 3);
+        assert_eq!(three.to_string(), "4-3-2");
+
+        // Paired, one of the aces plays
+        let three = evaluate_badugi(&cards("AsAh3d4h"));
+        assert_eq!(three.to_string(), "4-3-A");
+
+        let two = evaluate_badugi(&cards("As2s3h4h"));
+        assert_eq!(two.to_string(), "3-A");
+        assert!(two < three);
+
+        let one = evaluate_badugi(&cards("As2s3s4s"));
+        assert_eq!(one.to_string(), "A");
+        assert!(one < two);
+    }
+}
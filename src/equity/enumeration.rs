@@ -0,0 +1,131 @@
+use rayon::prelude::*;
+
+use crate::{
+    naive_evaluator::{card::Card, pocket::Pocket},
+    lookup_table_evaluator::indexing::{combinations_count, combination_into, next_combination}
+};
+
+use super::{Equity, EquityError, Showdown, BOARD_SIZE};
+
+/// Runouts enumerated by a single task
+const CHUNK_SIZE: usize = 10_000;
+
+/// Computes exact all-in equity by walking every possible board completion.
+/// Each runout is counted once, so counts are exact and ties are split between all tied players.
+/// 
+/// `board` - 0 to 5 known community cards, `dead` - cards which can't come on the board
+pub fn exact_equity(pockets: &[Pocket], board: &[Card], dead: &[Card]) -> Result<Equity, EquityError> {
+    let showdown = Showdown::new(pockets, board, dead)?;
+    let missing = showdown.missing_board_cards();
+    let runouts = combinations_count(showdown.remaining.cards().len(), missing);
+
+    let equity = (0..runouts.div_ceil(CHUNK_SIZE))
+        .into_par_iter()
+        .map(|chunk| {
+            let first = chunk * CHUNK_SIZE;
+            return enumerate(&showdown, first, CHUNK_SIZE.min(runouts - first));
+        })
+        .reduce(|| Equity::new(pockets.len()), Equity::merge);
+
+    return Ok(equity);
+}
+
+/// Evaluates `count` runouts starting from colexicographic index `first`
+pub(super) fn enumerate(showdown: &Showdown, first: usize, count: usize) -> Equity {
+    let mut equity = Equity::new(showdown.pockets.len());
+    let mut strengths = Vec::with_capacity(showdown.pockets.len());
+    let mut positions = [0; BOARD_SIZE];
+    let positions = &mut positions[0..showdown.missing_board_cards()];
+    combination_into(first, positions);
+
+    for _ in 0..count {
+        let mut board = showdown.board;
+        board.extend(positions.iter().map(|position| showdown.remaining.cards()[*position]));
+
+        showdown.evaluate(board, &mut strengths);
+        equity.record_showdown(&strengths);
+        next_combination(positions);
+    }
+
+    return equity;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{deck::Deck, evaluator::evaluate, fixtures::{card, pocket}};
+
+    use super::exact_equity;
+
+    #[test]
+    fn test_flop() {
+        // 45 cards left, C(45, 2) = 990 runouts
+        let flop = ["Qs", "7s", "2h"].map(card);
+        let equity = exact_equity(&[pocket("AsKs"), pocket("QhQd")], &flop, &[]).unwrap();
+        let (flush_draw, set) = (equity.players()[0], equity.players()[1]);
+
+        assert_eq!(equity.runouts(), 990);
+        assert_eq!(flush_draw.wins() + set.wins() + set.ties(), 990);
+        assert_eq!(flush_draw.ties(), set.ties());
+
+        let (pocket1, pocket2) = ([card("As"), card("Ks")], [card("Qh"), card("Qd")]);
+        let mut expected_wins = 0;
+        let remaining = Deck::without(&[pocket1[0], pocket1[1], pocket2[0], pocket2[1], flop[0], flop[1], flop[2]]).cards().clone();
+
+        for (i, turn) in remaining.iter().enumerate() {
+            for river in &remaining[i + 1..] {
+                let board = [flop[0], flop[1], flop[2], *turn, *river];
+                let ranking1 = evaluate(&[&pocket1[..], &board[..]].concat());
+                let ranking2 = evaluate(&[&pocket2[..], &board[..]].concat());
+                expected_wins += (ranking1 > ranking2) as u64;
+            }
+        }
+
+        assert_eq!(flush_draw.wins(), expected_wins);
+    }
+
+    #[test]
+    fn test_river_outs() {
+        // Flush draw against a set with 44 rivers left, 7 of the 9 spades win
+        let board = ["9s", "7s", "2h", "Td"].map(card);
+        let equity = exact_equity(&[pocket("AsKs"), pocket("9h9d")], &board, &[]).unwrap();
+
+        // 2s and Ts pair the board and give a full house to the set
+        assert_eq!(equity.runouts(), 44);
+        assert_eq!(equity.players()[0].wins(), 7);
+        assert_eq!(equity.players()[1].wins(), 37);
+    }
+
+    #[test]
+    fn test_aces_versus_kings_preflop() {
+        let equity = exact_equity(&[pocket("AsAh"), pocket("KsKh")], &[], &[]).unwrap();
+        let aces = equity.players()[0];
+
+        assert_eq!(equity.runouts(), 1_712_304);
+        assert_eq!(aces.wins(), 1_410_336);
+        assert_eq!(aces.ties(), 9_308);
+        assert!((aces.equity() - 0.8264).abs() < 1e-4, "{}", aces.equity());
+    }
+
+    #[test]
+    fn test_multiway_tie_shares() {
+        let board = ["Ah", "Kd", "Qc", "Jd"].map(card);
+        let equity = exact_equity(&[pocket("Ts2s"), pocket("Tc3d"), pocket("Td4h")], &board, &[card("Th")]).unwrap();
+
+        assert_eq!(equity.runouts(), 41);
+
+        for player in equity.players() {
+            assert_eq!(player.ties(), 41);
+            assert!((player.tie_share() - 41.0 / 3.0).abs() < 1e-9);
+            assert!((player.equity() - 1.0 / 3.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_complete_board() {
+        let board = ["Ah", "Kd", "7c", "7d", "2s"].map(card);
+        let equity = exact_equity(&[pocket("AsQs"), pocket("KsKh")], &board, &[]).unwrap();
+
+        assert_eq!(equity.runouts(), 1);
+        assert_eq!(equity.players()[1].wins(), 1);
+    }
+}
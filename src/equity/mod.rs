@@ -0,0 +1,245 @@
+use std::fmt::Display;
+
+use crate::naive_evaluator::{
+    card::Card,
+    card_set::CardSet,
+    deck::Deck,
+    pocket::Pocket,
+    hand_strength::HandStrength,
+    evaluator::{evaluate_card_set, CARDS_IN_COMBO}
+};
+
+pub mod monte_carlo;
+pub mod enumeration;
+pub mod range_equity;
+
+/// Number of community cards on the river
+pub const BOARD_SIZE: usize = CARDS_IN_COMBO;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EquityError {
+    /// Equity needs at least two players
+    TooFewPlayers,
+    /// Board has more than five cards
+    BoardTooLong(usize),
+    /// Card is used twice among pockets, board and dead cards
+    DuplicateCard(Card),
+    /// Remaining deck can't complete the board
+    NotEnoughCards,
+    /// Every combination of range combos shares a card
+    NoMatchups,
+    /// Sample budget allows no runouts
+    NoSamples
+}
+
+impl Display for EquityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            EquityError::TooFewPlayers          => write!(f, "at least two players are required"),
+            EquityError::BoardTooLong(length)   => write!(f, "board has {} cards, at most {} are allowed", length, BOARD_SIZE),
+            EquityError::DuplicateCard(card)    => write!(f, "card {} is used more than once", card),
+            EquityError::NotEnoughCards         => write!(f, "not enough cards left to complete the board"),
+            EquityError::NoMatchups             => write!(f, "ranges have no combos which don't share cards"),
+            EquityError::NoSamples              => write!(f, "at least one runout must be sampled"),
+        };
+    }
+}
+
+impl std::error::Error for EquityError {}
+
+/// Showdown results of a single player over all runouts
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PlayerEquity {
+    runouts: u64,
+    wins: u64,
+    ties: u64,
+    /// Sum of pot shares won in ties, e.g. 1/3 for each three way tie
+    tie_share: f64,
+    /// Sum of squared pot shares over all runouts, used for sampling error
+    share_squares: f64
+}
+
+impl PlayerEquity {
+    #[inline]
+    pub const fn runouts(&self) -> u64 {
+        return self.runouts;
+    }
+
+    /// Number of runouts won outright
+    #[inline]
+    pub const fn wins(&self) -> u64 {
+        return self.wins;
+    }
+
+    /// Number of runouts where pot is split with other players
+    #[inline]
+    pub const fn ties(&self) -> u64 {
+        return self.ties;
+    }
+
+    #[inline]
+    pub const fn losses(&self) -> u64 {
+        return self.runouts - self.wins - self.ties;
+    }
+
+    /// Pot shares won in ties, counting a three way tie as 1/3 and so on
+    #[inline]
+    pub const fn tie_share(&self) -> f64 {
+        return self.tie_share;
+    }
+
+    #[inline]
+    pub fn win(&self) -> f64 {
+        return self.wins as f64 / self.runouts as f64;
+    }
+
+    #[inline]
+    pub fn tie(&self) -> f64 {
+        return self.ties as f64 / self.runouts as f64;
+    }
+
+    #[inline]
+    pub fn lose(&self) -> f64 {
+        return self.losses() as f64 / self.runouts as f64;
+    }
+
+    /// Expected share of the pot
+    #[inline]
+    pub fn equity(&self) -> f64 {
+        return (self.wins as f64 + self.tie_share) / self.runouts as f64;
+    }
+
+    /// Standard error of [`PlayerEquity::equity`] when runouts are sampled
+    pub fn standard_error(&self) -> f64 {
+        if self.runouts < 2 {
+            return f64::INFINITY;
+        }
+
+        let n = self.runouts as f64;
+        let mean = self.equity();
+        let variance = (self.share_squares / n - mean * mean).max(0.0) * n / (n - 1.0);
+        return (variance / n).sqrt();
+    }
+
+    #[inline]
+    fn merge(&mut self, other: &PlayerEquity) {
+        self.runouts += other.runouts;
+        self.wins += other.wins;
+        self.ties += other.ties;
+        self.tie_share += other.tie_share;
+        self.share_squares += other.share_squares;
+    }
+}
+
+/// Equity of every player, in the same order as pockets were given
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equity {
+    players: Vec<PlayerEquity>
+}
+
+impl Equity {
+    #[inline]
+    fn new(players_count: usize) -> Self {
+        return Self { players: vec![PlayerEquity::default(); players_count] };
+    }
+
+    #[inline]
+    pub fn players(&self) -> &[PlayerEquity] {
+        return &self.players;
+    }
+
+    #[inline]
+    pub fn runouts(&self) -> u64 {
+        return self.players[0].runouts;
+    }
+
+    fn merge(mut self, other: Equity) -> Self {
+        for (player, other) in self.players.iter_mut().zip(&other.players) {
+            player.merge(other);
+        }
+
+        return self;
+    }
+
+    /// Records a single runout, `strengths` - hand of each player
+    fn record_showdown(&mut self, strengths: &[HandStrength]) {
+        let best = strengths.iter().max().unwrap();
+        let winners = strengths.iter().filter(|strength| *strength == best).count();
+        let share = 1.0 / winners as f64;
+
+        for (player, strength) in self.players.iter_mut().zip(strengths) {
+            player.runouts += 1;
+
+            if strength != best {
+                continue;
+            }
+
+            if winners == 1 {
+                player.wins += 1;
+            } else {
+                player.ties += 1;
+                player.tie_share += share;
+            }
+
+            player.share_squares += share * share;
+        }
+    }
+}
+
+/// Validated cards of an all-in situation
+struct Showdown {
+    pockets: Vec<CardSet>,
+    board: CardSet,
+    /// Cards which can still come on the board
+    remaining: Deck
+}
+
+impl Showdown {
+    fn new(pockets: &[Pocket], board: &[Card], dead: &[Card]) -> Result<Self, EquityError> {
+        if pockets.len() < 2 {
+            return Err(EquityError::TooFewPlayers);
+        }
+
+        if board.len() > BOARD_SIZE {
+            return Err(EquityError::BoardTooLong(board.len()));
+        }
+
+        let pocket_cards = pockets.iter().flat_map(|pocket| pocket.cards());
+        let known = distinct_cards(pocket_cards.chain(board).chain(dead))?;
+        let remaining = Deck::without_set(known);
+
+        if remaining.cards().len() < BOARD_SIZE - board.len() {
+            return Err(EquityError::NotEnoughCards);
+        }
+
+        return Ok(Self {
+            pockets: pockets.iter().map(|pocket| CardSet::from(&pocket.cards()[..])).collect(),
+            board: CardSet::from(board),
+            remaining
+        });
+    }
+
+    #[inline]
+    fn missing_board_cards(&self) -> usize {
+        return BOARD_SIZE - self.board.len();
+    }
+
+    /// Evaluates every player on the full board
+    fn evaluate(&self, board: CardSet, strengths: &mut Vec<HandStrength>) {
+        strengths.clear();
+        strengths.extend(self.pockets.iter().map(|pocket| HandStrength::from(evaluate_card_set(*pocket | board))));
+    }
+}
+
+/// Set of cards, fails if a card is given twice
+fn distinct_cards<'a>(cards: impl IntoIterator<Item = &'a Card>) -> Result<CardSet, EquityError> {
+    let mut set = CardSet::new();
+
+    for card in cards {
+        if !set.insert(*card) {
+            return Err(EquityError::DuplicateCard(*card));
+        }
+    }
+
+    return Ok(set);
+}
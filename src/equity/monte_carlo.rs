@@ -0,0 +1,203 @@
+use std::time::{Duration, Instant};
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rayon::prelude::*;
+
+use crate::naive_evaluator::{card::Card, pocket::Pocket};
+
+use super::{Equity, EquityError, Showdown};
+
+/// Runouts sampled by a single task between checks of the budget
+const BATCH_SIZE: u64 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleBudget {
+    /// Sample exactly this many runouts
+    Samples(u64),
+    /// Keep sampling until time runs out
+    Time(Duration)
+}
+
+impl SampleBudget {
+    /// Fails on a budget of zero samples, which would leave equity undefined
+    #[inline]
+    pub(super) fn validate(self) -> Result<Self, EquityError> {
+        if self == SampleBudget::Samples(0) {
+            return Err(EquityError::NoSamples);
+        }
+
+        return Ok(self);
+    }
+}
+
+/// Estimates all-in equity by sampling random board runouts from the remaining deck.
+/// 
+/// `board` - 0 to 5 known community cards, `dead` - cards which can't come on the board,
+/// `seed` - same seed gives the same result for [`SampleBudget::Samples`], random if `None`
+pub fn monte_carlo_equity(
+    pockets: &[Pocket], 
+    board: &[Card], 
+    dead: &[Card], 
+    budget: SampleBudget, 
+    seed: Option<u64>
+) -> Result<Equity, EquityError> {
+    let budget = budget.validate()?;
+    let showdown = Showdown::new(pockets, board, dead)?;
+
+    return Ok(run_batches(
+        budget,
+        seed,
+        || showdown.remaining.cards().clone(),
+        || Equity::new(pockets.len()),
+        |remaining, size, rng| sample(&showdown, remaining, size, rng),
+        Equity::merge
+    ));
+}
+
+/// Splits sampling between threads in batches of up to [`BATCH_SIZE`] samples.
+/// Each batch gets its own generator seeded from `seed` and the batch index,
+/// and batch results are merged in order, so a fixed number of samples is reproducible.
+/// 
+/// `scratch` - creates a buffer reused by all batches of one thread,
+/// `batch` - samples given number of runouts, `merge` - combines results of two batches
+pub(super) fn run_batches<S, T: Send>(
+    budget: SampleBudget,
+    seed: Option<u64>,
+    scratch: impl Fn() -> S + Sync + Send,
+    empty: impl Fn() -> T + Sync + Send,
+    batch: impl Fn(&mut S, u64, &mut StdRng) -> T + Sync + Send,
+    merge: impl Fn(T, T) -> T + Sync + Send
+) -> T {
+    let seed = seed.unwrap_or_else(|| rand::thread_rng().gen());
+    let rng = |index: u64| StdRng::seed_from_u64(seed.wrapping_add(index));
+
+    return match budget {
+        SampleBudget::Samples(samples) => {
+            (0..samples.div_ceil(BATCH_SIZE))
+                .into_par_iter()
+                .map_init(&scratch, |scratch, index| {
+                    let size = BATCH_SIZE.min(samples - index * BATCH_SIZE);
+                    return batch(scratch, size, &mut rng(index));
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .fold(empty(), &merge)
+        },
+        SampleBudget::Time(duration) => {
+            let deadline = Instant::now() + duration;
+
+            (0..rayon::current_num_threads() as u64)
+                .into_par_iter()
+                .map(|thread| {
+                    let mut rng = rng(thread);
+                    let mut scratch = scratch();
+                    let mut result = batch(&mut scratch, BATCH_SIZE, &mut rng);
+
+                    while Instant::now() < deadline {
+                        result = merge(result, batch(&mut scratch, BATCH_SIZE, &mut rng));
+                    }
+
+                    return result;
+                })
+                .reduce(&empty, &merge)
+        }
+    };
+}
+
+/// `remaining` - buffer for the remaining cards, reset at the start so a batch doesn't depend on previous ones
+fn sample(showdown: &Showdown, remaining: &mut [Card], samples: u64, rng: &mut impl Rng) -> Equity {
+    let mut equity = Equity::new(showdown.pockets.len());
+    remaining.copy_from_slice(showdown.remaining.cards());
+    let mut strengths = Vec::with_capacity(showdown.pockets.len());
+    let missing = showdown.missing_board_cards();
+
+    for _ in 0..samples {
+        let mut board = showdown.board;
+
+        // Partial Fisher-Yates: first `missing` cards become a uniformly random runout
+        for i in 0..missing {
+            let j = rng.gen_range(i..remaining.len());
+            remaining.swap(i, j);
+            board.insert(remaining[i]);
+        }
+
+        showdown.evaluate(board, &mut strengths);
+        equity.record_showdown(&strengths);
+    }
+
+    return equity;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        naive_evaluator::fixtures::{card, pocket},
+        equity::EquityError
+    };
+
+    use super::{monte_carlo_equity, SampleBudget};
+
+    #[test]
+    fn test_aces_versus_kings() {
+        let equity = monte_carlo_equity(&[pocket("AsAh"), pocket("KsKh")], &[], &[], SampleBudget::Samples(20_000), None).unwrap();
+        let aces = equity.players()[0];
+        let kings = equity.players()[1];
+
+        assert_eq!(equity.runouts(), 20_000);
+        assert!(aces.standard_error() < 0.005);
+        // Exact equity of AsAh against KsKh, ties count as half a win
+        assert!((aces.equity() - 0.8264).abs() < 4.0 * aces.standard_error(), "{}", aces.equity());
+        assert!((aces.equity() + kings.equity() - 1.0).abs() < 1e-9);
+        assert!((aces.win() + aces.tie() + aces.lose() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_complete_board() {
+        let board = ["Ah", "Kd", "7c", "7d", "2s"].map(card);
+        let equity = monte_carlo_equity(&[pocket("AsQs"), pocket("AdQd"), pocket("KsKh")], &board, &[], SampleBudget::Samples(100), None).unwrap();
+        
+        assert_eq!(equity.players()[2].wins(), 100);
+        assert_eq!(equity.players()[0].losses(), 100);
+        assert_eq!(equity.players()[2].standard_error(), 0.0);
+    }
+
+    #[test]
+    fn test_split_pot() {
+        let board = ["Ah", "Kd", "Qc", "Jd", "Ts"].map(card);
+        let equity = monte_carlo_equity(&[pocket("2s3s"), pocket("2d3d"), pocket("4h5h")], &board, &[], SampleBudget::Samples(10), None).unwrap();
+
+        for player in equity.players() {
+            assert_eq!(player.ties(), 10);
+            assert!((player.equity() - 1.0 / 3.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_seed_replays_simulation() {
+        let pockets = [pocket("AsKs"), pocket("QhQd"), pocket("7c6c")];
+        let simulate = |seed| monte_carlo_equity(&pockets, &[], &[], SampleBudget::Samples(5_500), seed).unwrap();
+
+        assert_eq!(simulate(Some(1)), simulate(Some(1)));
+        assert_ne!(simulate(Some(1)), simulate(Some(2)));
+    }
+
+    #[test]
+    fn test_time_budget() {
+        let equity = monte_carlo_equity(&[pocket("AsKs"), pocket("QhQd")], &["Qs", "7s", "2h"].map(card), &[card("Ts")], SampleBudget::Time(Duration::from_millis(50)), None).unwrap();
+        assert!(equity.runouts() > 0);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(monte_carlo_equity(&[pocket("AsKs")], &[], &[], SampleBudget::Samples(1), None), Err(EquityError::TooFewPlayers));
+        assert_eq!(monte_carlo_equity(&[pocket("AsKs"), pocket("AsQs")], &[], &[], SampleBudget::Samples(1), None), Err(EquityError::DuplicateCard(card("As"))));
+        assert_eq!(monte_carlo_equity(&[pocket("AsKs"), pocket("QsJs")], &[], &[card("Ks")], SampleBudget::Samples(1), None), Err(EquityError::DuplicateCard(card("Ks"))));
+        assert_eq!(
+            monte_carlo_equity(&[pocket("AsKs"), pocket("QsJs")], &["2c", "3c", "4c", "5c", "6c", "7c"].map(card), &[], SampleBudget::Samples(1), None), 
+            Err(EquityError::BoardTooLong(6))
+        );
+        assert_eq!(monte_carlo_equity(&[pocket("AsKs"), pocket("QsJs")], &[], &[], SampleBudget::Samples(0), None), Err(EquityError::NoSamples));
+    }
+}
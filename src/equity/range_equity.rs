@@ -0,0 +1,390 @@
+use rand::{Rng, distributions::{Distribution, WeightedIndex}};
+use rayon::prelude::*;
+
+use crate::{
+    naive_evaluator::{card::Card, card_set::CardSet, deck::Deck, pocket::Pocket, evaluator::evaluate_card_set, hand_strength::HandStrength},
+    lookup_table_evaluator::indexing::combinations_count,
+    range::Range
+};
+
+use super::{
+    Equity, EquityError, Showdown, BOARD_SIZE, distinct_cards,
+    enumeration::enumerate,
+    monte_carlo::{run_batches, SampleBudget}
+};
+
+/// Tries to draw combos which don't share cards before giving up on a sample
+const MAX_MATCHUP_ATTEMPTS: usize = 10_000;
+
+/// Equity of a single combo against the other ranges
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComboEquity {
+    combo: Pocket,
+    weight: f64,
+    equity: f64
+}
+
+impl ComboEquity {
+    #[inline]
+    pub const fn combo(&self) -> Pocket {
+        return self.combo;
+    }
+
+    /// Total weight of matchups the combo takes part in after card removal.
+    /// For sampled equity this is the number of samples.
+    #[inline]
+    pub const fn weight(&self) -> f64 {
+        return self.weight;
+    }
+
+    /// Expected share of the pot
+    #[inline]
+    pub const fn equity(&self) -> f64 {
+        return self.equity;
+    }
+}
+
+/// Equity of a whole range with a breakdown by combo
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeEquity {
+    equity: f64,
+    combos: Vec<ComboEquity>
+}
+
+impl RangeEquity {
+    /// Expected share of the pot
+    #[inline]
+    pub const fn equity(&self) -> f64 {
+        return self.equity;
+    }
+
+    /// Combos which have at least one matchup, in the same order as in range
+    #[inline]
+    pub fn combos(&self) -> &[ComboEquity] {
+        return &self.combos;
+    }
+}
+
+/// Range combos which don't collide with known cards
+struct RangeMatchups {
+    combos: Vec<Vec<(Pocket, f64)>>,
+    known: CardSet
+}
+
+impl RangeMatchups {
+    fn new(ranges: &[Range], board: &[Card], dead: &[Card]) -> Result<Self, EquityError> {
+        if ranges.len() < 2 {
+            return Err(EquityError::TooFewPlayers);
+        }
+
+        if board.len() > BOARD_SIZE {
+            return Err(EquityError::BoardTooLong(board.len()));
+        }
+
+        let known = distinct_cards(board.iter().chain(dead))?;
+
+        if Deck::SIZE - known.len() < 2 * ranges.len() + BOARD_SIZE - board.len() {
+            return Err(EquityError::NotEnoughCards);
+        }
+
+        let combos: Vec<Vec<_>> = ranges.iter()
+            .map(|range| {
+                return range.weighted_combos()
+                    .filter(|(combo, _)| known.is_disjoint(CardSet::from(&combo.cards()[..])))
+                    .map(|(combo, weight)| (*combo, weight))
+                    .collect();
+            })
+            .collect();
+
+        if combos.iter().any(|combos| combos.is_empty()) {
+            return Err(EquityError::NoMatchups);
+        }
+
+        return Ok(Self { combos, known });
+    }
+
+    /// Combo index in each range, `None` if combos share a card
+    fn matchup(&self, mut index: usize) -> Option<Vec<usize>> {
+        let mut used = self.known;
+        let mut matchup = Vec::with_capacity(self.combos.len());
+
+        for combos in &self.combos {
+            let combo = index % combos.len();
+            index /= combos.len();
+
+            for card in combos[combo].0.cards() {
+                if !used.insert(*card) {
+                    return None;
+                }
+            }
+
+            matchup.push(combo);
+        }
+
+        return Some(matchup);
+    }
+
+    #[inline]
+    fn matchups_count(&self) -> usize {
+        return self.combos.iter().map(Vec::len).product();
+    }
+
+    #[inline]
+    fn pockets(&self, matchup: &[usize]) -> Vec<Pocket> {
+        return matchup.iter().zip(&self.combos).map(|(combo, combos)| combos[*combo].0).collect();
+    }
+}
+
+/// Weight and weighted pot share of every combo of every range
+#[derive(Debug, Clone)]
+struct Accumulator {
+    combos: Vec<Vec<(f64, f64)>>
+}
+
+impl Accumulator {
+    fn new(matchups: &RangeMatchups) -> Self {
+        return Self { combos: matchups.combos.iter().map(|combos| vec![(0.0, 0.0); combos.len()]).collect() };
+    }
+
+    fn add(&mut self, matchup: &[usize], weight: f64, shares: impl Iterator<Item = f64>) {
+        for ((player, combo), share) in self.combos.iter_mut().zip(matchup).zip(shares) {
+            player[*combo].0 += weight;
+            player[*combo].1 += weight * share;
+        }
+    }
+
+    fn merge(mut self, other: Accumulator) -> Self {
+        for (player, other) in self.combos.iter_mut().zip(other.combos) {
+            for (combo, other) in player.iter_mut().zip(other) {
+                combo.0 += other.0;
+                combo.1 += other.1;
+            }
+        }
+
+        return self;
+    }
+
+    fn result(self, matchups: &RangeMatchups) -> Result<Vec<RangeEquity>, EquityError> {
+        return self.combos.into_iter()
+            .zip(&matchups.combos)
+            .map(|(totals, combos)| {
+                let weight: f64 = totals.iter().map(|(weight, _)| weight).sum();
+                let share: f64 = totals.iter().map(|(_, share)| share).sum();
+
+                if weight == 0.0 {
+                    return Err(EquityError::NoMatchups);
+                }
+
+                let combos = combos.iter()
+                    .zip(totals)
+                    .filter(|(_, (weight, _))| *weight > 0.0)
+                    .map(|((combo, _), (weight, share))| ComboEquity { combo: *combo, weight, equity: share / weight })
+                    .collect();
+
+                return Ok(RangeEquity { equity: share / weight, combos });
+            })
+            .collect();
+    }
+}
+
+/// Exact equity of ranges against each other.
+/// Every matchup of combos which don't share cards is weighted by product of combo weights
+/// and evaluated on every possible board completion, so this is meant for postflop boards or narrow ranges.
+pub fn range_equity(ranges: &[Range], board: &[Card], dead: &[Card]) -> Result<Vec<RangeEquity>, EquityError> {
+    let matchups = RangeMatchups::new(ranges, board, dead)?;
+    let missing = BOARD_SIZE - board.len();
+
+    let accumulator = (0..matchups.matchups_count())
+        .into_par_iter()
+        .fold(|| Accumulator::new(&matchups), |mut accumulator, index| {
+            let Some(matchup) = matchups.matchup(index) else {
+                return accumulator;
+            };
+
+            let weight: f64 = matchup.iter().zip(&matchups.combos).map(|(combo, combos)| combos[*combo].1).product();
+            let showdown = Showdown::new(&matchups.pockets(&matchup), board, dead)
+                .expect("matchup doesn't share cards");
+            let runouts = combinations_count(showdown.remaining.cards().len(), missing);
+            let equity = enumerate(&showdown, 0, runouts);
+
+            accumulator.add(&matchup, weight, equity.players().iter().map(|player| player.equity()));
+            return accumulator;
+        })
+        .reduce(|| Accumulator::new(&matchups), Accumulator::merge);
+
+    return accumulator.result(&matchups);
+}
+
+/// Estimates equity of ranges against each other by sampling combos proportionally to their weights
+/// together with random board runouts.
+/// 
+/// `seed` - same seed gives the same result for [`SampleBudget::Samples`], random if `None`
+pub fn monte_carlo_range_equity(
+    ranges: &[Range], 
+    board: &[Card], 
+    dead: &[Card], 
+    budget: SampleBudget, 
+    seed: Option<u64>
+) -> Result<Vec<RangeEquity>, EquityError> {
+    let budget = budget.validate()?;
+    let matchups = RangeMatchups::new(ranges, board, dead)?;
+    let board_set = CardSet::from(board);
+    let distributions = matchups.combos.iter()
+        .map(|combos| WeightedIndex::new(combos.iter().map(|(_, weight)| *weight)).map_err(|_| EquityError::NoMatchups))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let unknown = Deck::without_set(matchups.known);
+
+    let accumulator = run_batches(
+        budget,
+        seed,
+        || unknown.cards().clone(),
+        || Some(Accumulator::new(&matchups)),
+        |remaining, size, rng| {
+            let mut accumulator = Accumulator::new(&matchups);
+            let mut strengths = Vec::with_capacity(ranges.len());
+            remaining.copy_from_slice(unknown.cards());
+
+            for _ in 0..size {
+                let matchup = sample_matchup(&matchups, &distributions, rng)?;
+                let pockets = matchups.pockets(&matchup);
+                let mut used = matchups.known;
+                used.extend(pockets.iter().flat_map(|pocket| pocket.cards()).copied());
+
+                let mut runout = board_set;
+                let mut missing = BOARD_SIZE - board.len();
+                let mut i = 0;
+
+                // Partial Fisher-Yates over cards not known in advance, skipping cards in the pockets
+                while missing > 0 {
+                    let j = rng.gen_range(i..remaining.len());
+                    remaining.swap(i, j);
+
+                    if !used.contains(remaining[i]) {
+                        runout.insert(remaining[i]);
+                        missing -= 1;
+                    }
+
+                    i += 1;
+                }
+
+                strengths.clear();
+                strengths.extend(pockets.iter().map(|pocket| HandStrength::from(evaluate_card_set(CardSet::from(&pocket.cards()[..]) | runout))));
+
+                let mut equity = Equity::new(pockets.len());
+                equity.record_showdown(&strengths);
+                accumulator.add(&matchup, 1.0, equity.players().iter().map(|player| player.equity()));
+            }
+
+            return Some(accumulator);
+        },
+        |a, b| a.zip(b).map(|(a, b)| a.merge(b))
+    );
+
+    return accumulator.ok_or(EquityError::NoMatchups)?.result(&matchups);
+}
+
+fn sample_matchup(matchups: &RangeMatchups, distributions: &[WeightedIndex<f64>], rng: &mut impl Rng) -> Option<Vec<usize>> {
+    'attempt: for _ in 0..MAX_MATCHUP_ATTEMPTS {
+        let mut used = matchups.known;
+        let mut matchup = Vec::with_capacity(distributions.len());
+
+        for (distribution, combos) in distributions.iter().zip(&matchups.combos) {
+            let combo = distribution.sample(rng);
+
+            for card in combos[combo].0.cards() {
+                if !used.insert(*card) {
+                    continue 'attempt;
+                }
+            }
+
+            matchup.push(combo);
+        }
+
+        return Some(matchup);
+    }
+
+    return None;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        naive_evaluator::{deck::Deck, notation::parse_cards, fixtures::{card, pocket}},
+        equity::{EquityError, enumeration::exact_equity, monte_carlo::SampleBudget},
+        range::Range
+    };
+
+    use super::{range_equity, monte_carlo_range_equity};
+
+    fn range(value: &str) -> Range {
+        return Range::try_from(value).unwrap();
+    }
+
+    #[test]
+    fn test_single_combos_match_exact_equity() {
+        let board = ["Qs", "7s", "2h", "3d"].map(card);
+        let ranges = range_equity(&[range("AsKs"), range("QhQd")], &board, &[]).unwrap();
+        let exact = exact_equity(&[pocket("AsKs"), pocket("QhQd")], &board, &[]).unwrap();
+
+        assert!((ranges[0].equity() - exact.players()[0].equity()).abs() < 1e-12);
+        assert!((ranges[1].equity() - exact.players()[1].equity()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_card_removal() {
+        let board = ["Ah", "7d", "2c", "9s", "Jd"].map(card);
+        let ranges = range_equity(&[range("AA"), range("KsKd, KhKc")], &board, &[card("As")]).unwrap();
+
+        // Ah is on the board and As is dead, so only AdAc is left
+        assert_eq!(ranges[0].combos().len(), 1);
+        assert_eq!(ranges[0].combos()[0].combo(), pocket("AdAc"));
+        assert_eq!(ranges[0].equity(), 1.0);
+        assert_eq!(ranges[1].combos().len(), 2);
+        assert_eq!(ranges[1].equity(), 0.0);
+    }
+
+    #[test]
+    fn test_weights() {
+        // Aces always win and threes always lose against kings on this board
+        let board = ["Ah", "7d", "2c", "9s", "Jd"].map(card);
+        let ranges = range_equity(&[range("AsAc:0.25, 3s3h:0.75"), range("KsKd")], &board, &[]).unwrap();
+
+        assert!((ranges[0].equity() - 0.25).abs() < 1e-12);
+        assert!((ranges[1].equity() - 0.75).abs() < 1e-12);
+        assert_eq!(ranges[0].combos()[0].equity(), 0.0);
+        assert_eq!(ranges[0].combos()[0].weight(), 0.75);
+        assert_eq!(ranges[0].combos()[1].equity(), 1.0);
+    }
+
+    #[test]
+    fn test_monte_carlo_matches_exact() {
+        let board = ["Qs", "7s", "2h"].map(card);
+        let ranges = [range("AKs, 98s"), range("QQ, 77, AQo")];
+        let exact = range_equity(&ranges, &board, &[]).unwrap();
+        let sampled = monte_carlo_range_equity(&ranges, &board, &[], SampleBudget::Samples(20_000), None).unwrap();
+
+        assert!((exact[0].equity() - sampled[0].equity()).abs() < 0.02, "{} {}", exact[0].equity(), sampled[0].equity());
+        assert!((sampled[0].equity() + sampled[1].equity() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(range_equity(&[range("AhAs"), range("AhKh")], &[], &[]), Err(EquityError::NoMatchups));
+        assert_eq!(range_equity(&[range("AA")], &[], &[]), Err(EquityError::TooFewPlayers));
+        assert_eq!(range_equity(&[range("AhAs"), range("KK")], &[card("Ah")], &[]), Err(EquityError::NoMatchups));
+        assert_eq!(monte_carlo_range_equity(&[range("AhAs"), range("AhKh")], &[], &[], SampleBudget::Samples(10), None), Err(EquityError::NoMatchups));
+        assert_eq!(monte_carlo_range_equity(&[range("AA"), range("KK")], &[], &[], SampleBudget::Samples(0), None), Err(EquityError::NoSamples));
+
+        let dead = Deck::without(&parse_cards("AsAhKsKh2c3c").unwrap());
+        assert_eq!(range_equity(&[range("AA"), range("KK")], &[], dead.cards()), Err(EquityError::NotEnoughCards));
+        assert_eq!(monte_carlo_range_equity(&[range("AA"), range("KK")], &[], dead.cards(), SampleBudget::Samples(10), None), Err(EquityError::NotEnoughCards));
+    }
+
+    #[test]
+    fn test_seed_replays_simulation() {
+        let ranges = [range("QQ+, AK"), range("22+, A2s+")];
+        let simulate = |seed| monte_carlo_range_equity(&ranges, &[], &[], SampleBudget::Samples(3_000), seed).unwrap();
+        assert_eq!(simulate(Some(5)), simulate(Some(5)));
+    }
+}
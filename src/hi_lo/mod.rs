@@ -0,0 +1,248 @@
+use std::fmt::Display;
+
+use crate::{
+    naive_evaluator::{board::MAX_BOARD_SIZE, card::Card, hand::HAND_SIZE, hand_ranking::HandRanking, evaluator::evaluate},
+    lowball::ace_to_five::{AceToFiveLow, evaluate_eight_or_better},
+    omaha::{OmahaPocket, evaluate_omaha, evaluate_omaha_eight_or_better}
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitPotError {
+    /// Nobody reached the showdown
+    NoPlayers,
+    /// Board doesn't have all 5 community cards
+    IncompleteBoard(usize),
+    /// Stud player at the seat doesn't have 7 cards
+    WrongCardCount { seat: usize, count: usize },
+    /// Number of low hands differs from the number of high hands
+    MissingLowHands { highs: usize, lows: usize }
+}
+
+impl Display for SplitPotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            SplitPotError::NoPlayers                        => write!(f, "at least one player is required"),
+            SplitPotError::IncompleteBoard(length)          => write!(f, "board has {} cards, expected {}", length, MAX_BOARD_SIZE),
+            SplitPotError::WrongCardCount { seat, count }   => write!(f, "player at seat {} has {} cards, expected {}", seat, count, HAND_SIZE),
+            SplitPotError::MissingLowHands { highs, lows }  => write!(f, "got {} high hands and {} low hands", highs, lows),
+        };
+    }
+}
+
+impl std::error::Error for SplitPotError {}
+
+/// Half of a split pot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PotHalf {
+    High,
+    Low
+}
+
+/// Who gets the chips which can't be split evenly
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OddChipRules {
+    /// Half which gets the odd chip when the pot is split between high and low
+    pub half: PotHalf,
+    /// Seat left of the button. Odd chips of a tied half go one by one to winners
+    /// starting from this seat clockwise.
+    pub first_seat: usize
+}
+
+impl Default for OddChipRules {
+    #[inline]
+    fn default() -> Self {
+        return Self { half: PotHalf::High, first_seat: 0 };
+    }
+}
+
+/// Result of a hi-lo showdown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitPot {
+    shares: Vec<u64>,
+    high_winners: Vec<usize>,
+    low_winners: Vec<usize>
+}
+
+impl SplitPot {
+    /// Chips won by each player
+    #[inline]
+    pub fn shares(&self) -> &[u64] {
+        return &self.shares;
+    }
+
+    #[inline]
+    pub fn high_winners(&self) -> &[usize] {
+        return &self.high_winners;
+    }
+
+    /// Empty if nobody has a qualifying low
+    #[inline]
+    pub fn low_winners(&self) -> &[usize] {
+        return &self.low_winners;
+    }
+
+    /// Player who won the whole pot alone
+    pub fn scooper(&self) -> Option<usize> {
+        let high_scoop = self.high_winners.len() == 1 && self.low_winners.iter().all(|low| *low == self.high_winners[0]);
+        return Some(self.high_winners[0]).filter(|_| high_scoop);
+    }
+}
+
+/// Splits the pot of Omaha/8 between players who reached the showdown
+/// 
+/// `board` - 5 community cards
+pub fn split_omaha_pot(pockets: &[OmahaPocket], board: &[Card], pot: u64, odd_chip: OddChipRules) -> Result<SplitPot, SplitPotError> {
+    if board.len() != MAX_BOARD_SIZE {
+        return Err(SplitPotError::IncompleteBoard(board.len()));
+    }
+
+    let highs: Vec<_> = pockets.iter().map(|pocket| evaluate_omaha(pocket, board)).collect();
+    let lows: Vec<_> = pockets.iter().map(|pocket| evaluate_omaha_eight_or_better(pocket, board)).collect();
+    return split_pot(&highs, &lows, pot, odd_chip);
+}
+
+/// Splits the pot of Stud/8 between players who reached the showdown, each player has 7 cards
+pub fn split_stud_pot(hands: &[&[Card]], pot: u64, odd_chip: OddChipRules) -> Result<SplitPot, SplitPotError> {
+    if let Some(seat) = hands.iter().position(|hand| hand.len() != HAND_SIZE) {
+        return Err(SplitPotError::WrongCardCount { seat, count: hands[seat].len() });
+    }
+
+    let highs: Vec<_> = hands.iter().map(|hand| evaluate(hand)).collect();
+    let lows: Vec<_> = hands.iter().map(|hand| evaluate_eight_or_better(hand)).collect();
+    return split_pot(&highs, &lows, pot, odd_chip);
+}
+
+/// Gives half of the pot to the best high hands and half to the best qualifying lows.
+/// The whole pot goes to the high hands if nobody has a low.
+/// 
+/// `highs`, `lows` - hands of each player in seat order
+pub fn split_pot(highs: &[HandRanking], lows: &[Option<AceToFiveLow>], pot: u64, odd_chip: OddChipRules) -> Result<SplitPot, SplitPotError> {
+    if highs.is_empty() {
+        return Err(SplitPotError::NoPlayers);
+    }
+
+    if highs.len() != lows.len() {
+        return Err(SplitPotError::MissingLowHands { highs: highs.len(), lows: lows.len() });
+    }
+
+    let high_winners = winners(highs);
+    let low_winners = if lows.iter().any(Option::is_some) { winners(lows) } else { Vec::new() };
+
+    let mut shares = vec![0; highs.len()];
+
+    if low_winners.is_empty() {
+        award(pot, &high_winners, odd_chip.first_seat, &mut shares);
+    } else {
+        let (high, low) = match odd_chip.half {
+            PotHalf::High   => (pot - pot / 2, pot / 2),
+            PotHalf::Low    => (pot / 2, pot - pot / 2)
+        };
+
+        award(high, &high_winners, odd_chip.first_seat, &mut shares);
+        award(low, &low_winners, odd_chip.first_seat, &mut shares);
+    }
+
+    return Ok(SplitPot { shares, high_winners, low_winners });
+}
+
+/// Seats of the best hands
+fn winners<T: Ord>(hands: &[T]) -> Vec<usize> {
+    let best = hands.iter().max().unwrap();
+    return (0..hands.len()).filter(|seat| hands[*seat] == *best).collect();
+}
+
+/// Splits chips evenly between winners, leftover chips go one by one starting from `first_seat` clockwise
+fn award(chips: u64, winners: &[usize], first_seat: usize, shares: &mut [u64]) {
+    let share = chips / winners.len() as u64;
+    let mut leftover = chips % winners.len() as u64;
+
+    let mut ordered = winners.to_vec();
+    ordered.sort_unstable_by_key(|seat| (*seat + shares.len() - first_seat % shares.len()) % shares.len());
+
+    for seat in ordered {
+        shares[seat] += share;
+
+        if leftover > 0 {
+            shares[seat] += 1;
+            leftover -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{naive_evaluator::notation::parse_cards, omaha::OmahaPocket};
+
+    use super::{split_omaha_pot, split_pot, split_stud_pot, OddChipRules, PotHalf, SplitPotError};
+
+    fn pockets(values: &[&str]) -> Vec<OmahaPocket> {
+        return values.iter().map(|value| OmahaPocket::try_from(*value).unwrap()).collect();
+    }
+
+    #[test]
+    fn test_scoop() {
+        let board = parse_cards("2h5d8cKsKh").unwrap();
+        let pot = split_omaha_pot(&pockets(&["AhKd3c9d", "QsQdJcTc"]), &board, 100, OddChipRules::default()).unwrap();
+        assert_eq!(pot.shares(), &[100, 0]);
+        assert_eq!(pot.scooper(), Some(0));
+
+        // No qualifying low, high takes all
+        let board = parse_cards("2h9dTcKsKh").unwrap();
+        let pot = split_omaha_pot(&pockets(&["Ah3d4c9c", "QsQdJcTd"]), &board, 100, OddChipRules::default()).unwrap();
+        assert_eq!(pot.shares(), &[0, 100]);
+        assert!(pot.low_winners().is_empty());
+        assert_eq!(pot.scooper(), Some(1));
+    }
+
+    #[test]
+    fn test_split_and_odd_chip() {
+        let board = parse_cards("2h5d8cKsQh").unwrap();
+        let players = pockets(&["Ah3d9c9d", "KdKcJcTc"]);
+
+        let pot = split_omaha_pot(&players, &board, 101, OddChipRules::default()).unwrap();
+        assert_eq!(pot.shares(), &[50, 51]);
+        assert_eq!(pot.scooper(), None);
+
+        let pot = split_omaha_pot(&players, &board, 101, OddChipRules { half: PotHalf::Low, first_seat: 0 }).unwrap();
+        assert_eq!(pot.shares(), &[51, 50]);
+    }
+
+    #[test]
+    fn test_quartering() {
+        let board = parse_cards("2h5d8cKsQh").unwrap();
+        let pot = split_omaha_pot(&pockets(&["Ah3dKdKc", "As3cJcTc", "QsQdJsTs"]), &board, 100, OddChipRules::default()).unwrap();
+        assert_eq!(pot.high_winners(), &[0]);
+        assert_eq!(pot.low_winners(), &[0, 1]);
+        assert_eq!(pot.shares(), &[75, 25, 0]);
+    }
+
+    #[test]
+    fn test_tied_odd_chips_go_left_of_button() {
+        let hands = [parse_cards("AsKs2c3d4h6c9d").unwrap(), parse_cards("AhKh2d3c4s6s9h").unwrap(), parse_cards("KdQcJcTc8d7d6d").unwrap()];
+        let hands: Vec<_> = hands.iter().map(|hand| &hand[..]).collect();
+
+        let pot = split_stud_pot(&hands, 12, OddChipRules::default()).unwrap();
+        assert_eq!(pot.high_winners(), &[0, 1]);
+        assert_eq!(pot.low_winners(), &[0, 1]);
+        assert_eq!(pot.shares(), &[6, 6, 0]);
+
+        let pot = split_stud_pot(&hands, 11, OddChipRules { half: PotHalf::High, first_seat: 1 }).unwrap();
+        assert_eq!(pot.shares(), &[5, 6, 0]);
+
+        let pot = split_stud_pot(&hands, 13, OddChipRules { half: PotHalf::High, first_seat: 2 }).unwrap();
+        assert_eq!(pot.shares(), &[7, 6, 0]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let board = parse_cards("2h5d8cKs").unwrap();
+        assert_eq!(split_omaha_pot(&pockets(&["AhKd3c9d", "QsQdJcTc"]), &board, 100, OddChipRules::default()), Err(SplitPotError::IncompleteBoard(4)));
+
+        let hands = [parse_cards("AsKs2c3d4h6c9d").unwrap(), parse_cards("AhKh2d3c4s6s").unwrap()];
+        let hands: Vec<_> = hands.iter().map(|hand| &hand[..]).collect();
+        assert_eq!(split_stud_pot(&hands, 10, OddChipRules::default()), Err(SplitPotError::WrongCardCount { seat: 1, count: 6 }));
+
+        assert_eq!(split_pot(&[], &[], 10, OddChipRules::default()), Err(SplitPotError::NoPlayers));
+        assert_eq!(split_stud_pot(&[], 10, OddChipRules::default()), Err(SplitPotError::NoPlayers));
+    }
+}
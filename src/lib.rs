@@ -1,2 +1,13 @@
+#![allow(clippy::needless_return)]
+
 pub mod naive_evaluator;
 pub mod lookup_table_evaluator;
+pub mod equity;
+pub mod range;
+pub mod omaha;
+pub mod short_deck;
+pub mod lowball;
+pub mod hi_lo;
+pub mod badugi;
+pub mod stud;
+pub mod wild;
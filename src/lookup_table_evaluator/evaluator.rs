@@ -0,0 +1,60 @@
+use rayon::prelude::*;
+
+use crate::naive_evaluator::{
+    hand::Hand,
+    hand_strength::HandStrength,
+    evaluator::evaluate_five_cards
+};
+
+use super::lookup_table_generation::HAND_COMBINATIONS_COUNT;
+
+/// Evaluates 7-card hands with a single read from a table precomputed for every possible hand
+pub struct LookupTableEvaluator {
+    table: Vec<HandStrength>
+}
+
+impl LookupTableEvaluator {
+    /// Evaluates all 133 784 560 hands with the naive evaluator.
+    /// This is slow and takes about 270 MB of memory, so build it once and reuse it.
+    pub fn new() -> Self {
+        let table = (0..HAND_COMBINATIONS_COUNT)
+            .into_par_iter()
+            .map(|index| HandStrength::from(evaluate_five_cards(&Hand::from_index(index))))
+            .collect();
+
+        return Self { table };
+    }
+
+    #[inline]
+    pub fn evaluate(&self, hand: &Hand) -> HandStrength {
+        return self.table[hand.index()];
+    }
+}
+
+impl Default for LookupTableEvaluator {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        naive_evaluator::{hand::Hand, hand_strength::HandStrength, evaluator::evaluate_five_cards},
+        lookup_table_evaluator::lookup_table_generation::HAND_COMBINATIONS_COUNT
+    };
+
+    use super::LookupTableEvaluator;
+
+    #[test]
+    #[ignore = "builds the full table, run in release mode"]
+    fn test_matches_naive_evaluator() {
+        let evaluator = LookupTableEvaluator::new();
+
+        for index in 0..HAND_COMBINATIONS_COUNT {
+            let hand = Hand::from_index(index);
+            assert_eq!(evaluator.evaluate(&hand), HandStrength::from(evaluate_five_cards(&hand)), "{}", hand);
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::naive_evaluator::{
+    card::Card,
+    deck::Deck,
+    hand::{Hand, HAND_SIZE}
+};
+
+pub const FIVE_CARD_COMBINATIONS_COUNT: usize = 2_598_960;
+pub const SIX_CARD_COMBINATIONS_COUNT: usize = 20_358_520;
+pub const SEVEN_CARD_COMBINATIONS_COUNT: usize = 133_784_560;
+
+/// Largest combination size supported by indexing
+pub const MAX_COMBINATION_SIZE: usize = HAND_SIZE;
+
+/// Binomial coefficients `C(n, k)` for `n` in `0..=52` and `k` in `0..=7`
+const BINOMIALS: [[usize; MAX_COMBINATION_SIZE + 1]; Deck::SIZE + 1] = binomials();
+
+const fn binomials() -> [[usize; MAX_COMBINATION_SIZE + 1]; Deck::SIZE + 1] {
+    let mut table = [[0; MAX_COMBINATION_SIZE + 1]; Deck::SIZE + 1];
+    let mut n = 0;
+
+    while n <= Deck::SIZE {
+        table[n][0] = 1;
+        let mut k = 1;
+
+        while k <= MAX_COMBINATION_SIZE && k <= n {
+            table[n][k] = table[n - 1][k - 1] + table[n - 1][k];
+            k += 1;
+        }
+
+        n += 1;
+    }
+
+    return table;
+}
+
+/// Number of `k` element combinations out of `n`, `n <= 52` and `k <= 7`
+#[inline]
+pub const fn combinations_count(n: usize, k: usize) -> usize {
+    return BINOMIALS[n][k];
+}
+
+/// Colexicographic rank of a combination.
+/// 
+/// `positions` - distinct element positions in ascending order.
+/// Result is in `0..combinations_count(n, positions.len())` for any `n` greater than the last position.
+pub fn index_of_combination(positions: &[usize]) -> usize {
+    debug_assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+
+    return positions.iter()
+        .enumerate()
+        .map(|(i, position)| BINOMIALS[*position][i + 1])
+        .sum();
+}
+
+/// Inverse of [`index_of_combination`], returns positions in ascending order
+#[inline]
+pub fn combination_at<const K: usize>(index: usize) -> [usize; K] {
+    let mut positions = [0; K];
+    combination_into(index, &mut positions);
+    return positions;
+}
+
+/// Same as [`combination_at`] for combinations which size is known only at runtime
+pub fn combination_into(mut index: usize, positions: &mut [usize]) {
+    debug_assert!(positions.len() <= MAX_COMBINATION_SIZE);
+
+    let mut position = Deck::SIZE + 1;
+
+    for k in (1..=positions.len()).rev() {
+        position -= 1;
+
+        while BINOMIALS[position][k] > index {
+            position -= 1;
+        }
+
+        index -= BINOMIALS[position][k];
+        positions[k - 1] = position;
+    }
+}
+
+/// Advances positions in ascending order to the combination with the next colexicographic index
+pub fn next_combination(positions: &mut [usize]) {
+    for i in 0..positions.len() {
+        let is_last = i + 1 == positions.len();
+
+        if is_last || positions[i] + 1 < positions[i + 1] {
+            positions[i] += 1;
+
+            for (j, position) in positions[0..i].iter_mut().enumerate() {
+                *position = j;
+            }
+
+            return;
+        }
+    }
+}
+
+/// Dense index of a 5, 6 or 7 card set in `0..combinations_count(52, cards.len())`, cards can be in any order
+pub fn index_of_cards(cards: &[Card]) -> usize {
+    debug_assert!(cards.len() <= MAX_COMBINATION_SIZE);
+
+    let mut positions = [0; MAX_COMBINATION_SIZE];
+
+    for (position, card) in positions.iter_mut().zip(cards) {
+        *position = card.index();
+    }
+
+    let positions = &mut positions[0..cards.len()];
+    positions.sort_unstable();
+
+    return index_of_combination(positions);
+}
+
+/// Inverse of [`index_of_cards`], returns cards in ascending order
+#[inline]
+pub fn cards_at<const K: usize>(index: usize) -> [Card; K] {
+    return combination_at::<K>(index).map(Card::from_index);
+}
+
+impl Hand {
+    /// Dense index of the hand in `0..HAND_COMBINATIONS_COUNT`
+    #[inline]
+    pub fn index(&self) -> usize {
+        return index_of_cards(self.cards());
+    }
+
+    /// Inverse of [`Hand::index`]
+    #[inline]
+    pub fn from_index(index: usize) -> Self {
+        return Hand::new(cards_at::<HAND_SIZE>(index));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{card::Card, hand::Hand};
+
+    use super::{
+        index_of_cards, cards_at, combinations_count, combination_at, index_of_combination, next_combination,
+        FIVE_CARD_COMBINATIONS_COUNT, SIX_CARD_COMBINATIONS_COUNT, SEVEN_CARD_COMBINATIONS_COUNT
+    };
+
+    #[test]
+    fn test_combinations_count() {
+        assert_eq!(combinations_count(52, 5), FIVE_CARD_COMBINATIONS_COUNT);
+        assert_eq!(combinations_count(52, 6), SIX_CARD_COMBINATIONS_COUNT);
+        assert_eq!(combinations_count(52, 7), SEVEN_CARD_COMBINATIONS_COUNT);
+    }
+
+    #[test]
+    fn test_five_cards_bijection() {
+        for index in 0..FIVE_CARD_COMBINATIONS_COUNT {
+            assert_eq!(index_of_cards(&cards_at::<5>(index)), index);
+        }
+    }
+
+    #[test]
+    fn test_six_cards_round_trip() {
+        assert_eq!(index_of_cards(&cards_at::<6>(SIX_CARD_COMBINATIONS_COUNT - 1)), SIX_CARD_COMBINATIONS_COUNT - 1);
+
+        for index in (0..SIX_CARD_COMBINATIONS_COUNT).step_by(99_991) {
+            assert_eq!(index_of_cards(&cards_at::<6>(index)), index);
+        }
+    }
+
+    #[test]
+    fn test_hand_index_round_trip() {
+        assert_eq!(Hand::from_index(0).index(), 0);
+        assert_eq!(Hand::from_index(SEVEN_CARD_COMBINATIONS_COUNT - 1).index(), SEVEN_CARD_COMBINATIONS_COUNT - 1);
+
+        for index in (0..SEVEN_CARD_COMBINATIONS_COUNT).step_by(999_983) {
+            assert_eq!(Hand::from_index(index).index(), index);
+        }
+
+        let hand = Hand::try_from("AsAcQd5s4s3s2s").unwrap();
+        assert_eq!(Hand::from_index(hand.index()), hand);
+    }
+
+    #[test]
+    fn test_card_order_independent() {
+        let cards = [Card::try_from("Kd").unwrap(), Card::try_from("2c").unwrap(), Card::try_from("As").unwrap(), Card::try_from("7h").unwrap(), Card::try_from("Tc").unwrap()];
+        let mut sorted = cards;
+        sorted.sort_unstable();
+        assert_eq!(index_of_cards(&cards), index_of_cards(&sorted));
+        assert_eq!(cards_at::<5>(index_of_cards(&cards)), sorted);
+    }
+
+    #[test]
+    fn test_next_combination() {
+        let mut positions = combination_at::<3>(0);
+        assert_eq!(positions, [0, 1, 2]);
+
+        for index in 1..combinations_count(20, 3) {
+            next_combination(&mut positions);
+            assert_eq!(index_of_combination(&positions), index);
+            assert_eq!(positions, combination_at::<3>(index));
+        }
+    }
+}
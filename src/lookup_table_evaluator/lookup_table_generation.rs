@@ -1,13 +1,15 @@
-use crate::naive_evaluator::{deck::Deck, card::Card, hand::{HAND_SIZE, Hand}};
+use crate::naive_evaluator::{deck::Deck, card::Card, card_set::CardSet, hand::{HAND_SIZE, Hand}};
+
+use super::indexing::SEVEN_CARD_COMBINATIONS_COUNT;
 
 // const HAND_PERMUTATIONS_COUNT: usize = 674_274_182_400;
-pub const HAND_COMBINATIONS_COUNT: usize = 133_784_560;
+pub const HAND_COMBINATIONS_COUNT: usize = SEVEN_CARD_COMBINATIONS_COUNT;
 
 pub struct HandsGenerator {
     hands: Vec<Hand>,
     hand: Vec<Card>,
     deck: Deck,
-    used_cards: [bool; Deck::SIZE]
+    used_cards: CardSet
 }
 
 impl HandsGenerator {
@@ -15,7 +17,7 @@ impl HandsGenerator {
         return Self {
             deck: Deck::new(),
             hands: Vec::with_capacity(HAND_COMBINATIONS_COUNT),
-            used_cards: [false; Deck::SIZE],
+            used_cards: CardSet::new(),
             hand: Vec::with_capacity(HAND_SIZE)
         };
     }
@@ -36,16 +38,17 @@ impl HandsGenerator {
         }
 
         for i in index..Deck::SIZE {
-            if self.used_cards[i] {
+            let card = self.deck.cards()[i];
+
+            if !self.used_cards.insert(card) {
                 continue;
             }
 
-            self.used_cards[i] = true;
-            self.hand.push(self.deck.cards()[i]);
+            self.hand.push(card);
 
             self.generate_i(i + 1);
 
-            self.used_cards[i] = false;      
+            self.used_cards.remove(card);
             self.hand.pop();
         }  
 
@@ -54,3 +57,10 @@ impl HandsGenerator {
         }
     }
 }
+
+impl Default for HandsGenerator {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
@@ -1,17 +1,18 @@
 /// 
 /// Number of combinations: 133784560
 ///
-/// Hashing:
-/// Card 6 bits total:
-///     4 bits for rank, 2 ^ 4 = 16, only 13 are used because we have 13 diff ranks
-///     2 bits for suit, 2 ^ 2 = 4, one value for each suit
-/// In fact 1 byte (8 bits) is used
+/// Indexing:
+/// Card is numbered `rank * 4 + suit`, so card numbers go in the same order as cards:
+///     2c - 0, 2d - 1, 2h - 2, 2s - 3, 3c - 4, ..., As - 51
 ///
-/// Hand 7 * 8 = 56 bits
-/// In fact 64 bits (8 bytes) is used (u64)
+/// A set of k cards with numbers c1 < c2 < ... < ck is ranked in colexicographic order:
+///     index = C(c1, 1) + C(c2, 2) + ... + C(ck, k)
+/// This is a bijection between k-card sets and `0..C(52, k)`, so there are no gaps in the key space.
+/// See `indexing` module.
+/// 
+/// Table stores u16 hand strength for each 7-card hand
+/// Table size = 2 * 133784560 = 267 569 120 bytes ~ 270 MB
 /// 
-/// Table size = 8 * 133784560 = 1 070 276 480 bytes ~ 1 GB
-///  
 /// 2  - 0
 /// 3  - 1
 /// 4  - 2
@@ -26,13 +27,11 @@
 /// K  - 11
 /// A  - 12
 /// 
-/// s - 0
+/// c - 0
 /// d - 1
-/// c - 2
-/// h - 3 
-/// 
-/// 
-/// 
+/// h - 2
+/// s - 3 
 ///
 pub mod lookup_table_generation;
- 
\ No newline at end of file
+pub mod indexing;
+pub mod evaluator;
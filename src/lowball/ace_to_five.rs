@@ -0,0 +1,169 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::naive_evaluator::{
+    card::{Card, Rank},
+    hand_ranking::HandCategory,
+    evaluator::{five_card_combos, CARDS_IN_COMBO}
+};
+
+/// Ace-to-five low hand. Aces are low, straights and flushes don't count, so 5-4-3-2-A is the best hand.
+/// Better low hands compare greater.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AceToFiveLow {
+    /// Only high card (no pair) and paired categories are possible
+    category: HandCategory,
+    /// Paired ranks first, then side cards, from high to low counting Ace as one
+    ranks: [Rank; CARDS_IN_COMBO]
+}
+
+impl AceToFiveLow {
+    #[inline]
+    pub const fn category(&self) -> HandCategory {
+        return self.category;
+    }
+
+    #[inline]
+    pub const fn ranks(&self) -> &[Rank; CARDS_IN_COMBO] {
+        return &self.ranks;
+    }
+
+    /// Hand has no pairs and no card above `highest`, e.g. Eight for eight-or-better
+    #[inline]
+    pub fn qualifies(&self, highest: Rank) -> bool {
+        return self.category == HandCategory::HighCard && ace_low_value(self.ranks[0]) <= ace_low_value(highest);
+    }
+
+    /// Smaller key is a better low
+    #[inline]
+    fn key(&self) -> (HandCategory, [u8; CARDS_IN_COMBO]) {
+        return (self.category, self.ranks.map(ace_low_value));
+    }
+}
+
+impl Ord for AceToFiveLow {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        return other.key().cmp(&self.key());
+    }
+}
+
+impl PartialOrd for AceToFiveLow {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Display for AceToFiveLow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}", self.ranks.map(|rank| rank.to_string()).join("-"));
+    }
+}
+
+/// Ace counts as one
+#[inline]
+pub(crate) fn ace_low_value(rank: Rank) -> u8 {
+    if rank == Rank::Ace {
+        return 1;
+    }
+
+    return rank as u8 + 2;
+}
+
+/// Best ace-to-five low out of 5 to 7 cards, for Razz and the low half of Stud/8
+pub fn evaluate_ace_to_five(cards: &[Card]) -> AceToFiveLow {
+    return five_card_combos(cards)
+        .map(|five| five_card_low(five.map(|card| card.rank())))
+        .max()
+        .unwrap();
+}
+
+/// Best ace-to-five low with no pair and no card above Eight, `None` if there is no qualifying low
+#[inline]
+pub fn evaluate_eight_or_better(cards: &[Card]) -> Option<AceToFiveLow> {
+    return Some(evaluate_ace_to_five(cards)).filter(|low| low.qualifies(Rank::Eight));
+}
+
+fn five_card_low(mut ranks: [Rank; CARDS_IN_COMBO]) -> AceToFiveLow {
+    let count = |rank: Rank| ranks.iter().filter(|other| **other == rank).count();
+    let counts = ranks.map(count);
+    let mut order: Vec<_> = ranks.iter().copied().zip(counts).collect();
+    order.sort_unstable_by(|(r1, c1), (r2, c2)| c2.cmp(c1).then(ace_low_value(*r2).cmp(&ace_low_value(*r1))));
+
+    for (rank, (ordered, _)) in ranks.iter_mut().zip(order.iter()) {
+        *rank = *ordered;
+    }
+
+    let pairs = counts.iter().filter(|count| **count == 2).count() / 2;
+    let category = match counts.iter().max().unwrap() {
+        1 => HandCategory::HighCard,
+        2 if pairs == 1 => HandCategory::Pair,
+        2 => HandCategory::TwoPair,
+        3 if pairs == 1 => HandCategory::FullHouse,
+        3 => HandCategory::ThreeOf,
+        _ => HandCategory::FourOf
+    };
+
+    return AceToFiveLow { category, ranks };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{card::Rank, hand_ranking::HandCategory, notation::parse_cards};
+
+    use super::{evaluate_ace_to_five, evaluate_eight_or_better};
+
+    #[test]
+    fn test_wheel_is_best() {
+        let wheel = evaluate_ace_to_five(&parse_cards("5h4h3h2hAh").unwrap());
+        assert_eq!(wheel.category(), HandCategory::HighCard);
+        assert_eq!(wheel.ranks(), &[Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]);
+        assert_eq!(wheel.to_string(), "5-4-3-2-A");
+
+        assert!(wheel > evaluate_ace_to_five(&parse_cards("6s4h3h2hAh").unwrap()));
+        assert!(wheel > evaluate_ace_to_five(&parse_cards("5s4h3h2h2c").unwrap()));
+    }
+
+    #[test]
+    fn test_compares_from_highest_card() {
+        let low1 = evaluate_ace_to_five(&parse_cards("8s6d4c3hAs").unwrap());
+        let low2 = evaluate_ace_to_five(&parse_cards("8d7c2c3dAd").unwrap());
+        let low3 = evaluate_ace_to_five(&parse_cards("9d2c3c4dAd").unwrap());
+        assert!(low1 > low2);
+        assert!(low2 > low3);
+    }
+
+    #[test]
+    fn test_razz_seven_cards() {
+        let low = evaluate_ace_to_five(&parse_cards("KsKd8c7h4s2dAc").unwrap());
+        assert_eq!(low.to_string(), "8-7-4-2-A");
+
+        // Only four distinct ranks, so one pair has to be used
+        let low = evaluate_ace_to_five(&parse_cards("4s4d3c3hAsAd2c").unwrap());
+        assert_eq!(low.category(), HandCategory::Pair);
+        assert_eq!(low.to_string(), "A-A-4-3-2");
+        assert!(low < evaluate_ace_to_five(&parse_cards("KsQdJc9h8s").unwrap()));
+        assert!(low > evaluate_ace_to_five(&parse_cards("2s2d3c4h5s").unwrap()));
+    }
+
+    #[test]
+    fn test_paired_hands() {
+        let pair = evaluate_ace_to_five(&parse_cards("KsKdQcJhTs").unwrap());
+        let two_pair = evaluate_ace_to_five(&parse_cards("2s2d3c3hAs").unwrap());
+        let trips = evaluate_ace_to_five(&parse_cards("2s2d2c3hAs").unwrap());
+        let full_house = evaluate_ace_to_five(&parse_cards("2s2d2c3h3s").unwrap());
+        let quads = evaluate_ace_to_five(&parse_cards("AsAdAcAh2s").unwrap());
+
+        assert_eq!(two_pair.category(), HandCategory::TwoPair);
+        assert_eq!(full_house.category(), HandCategory::FullHouse);
+        assert!(pair > two_pair && two_pair > trips && trips > full_house && full_house > quads);
+    }
+
+    #[test]
+    fn test_eight_or_better() {
+        assert_eq!(evaluate_eight_or_better(&parse_cards("9s7d5c3hAs").unwrap()), None);
+        assert_eq!(evaluate_eight_or_better(&parse_cards("8s8d5c3hAs").unwrap()), None);
+        assert!(evaluate_eight_or_better(&parse_cards("8s7d5c3hAs").unwrap()).is_some());
+        assert_eq!(evaluate_eight_or_better(&parse_cards("KsQd8s7d5c3hAs").unwrap()).unwrap().to_string(), "8-7-5-3-A");
+    }
+}
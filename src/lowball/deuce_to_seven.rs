@@ -0,0 +1,101 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::naive_evaluator::{
+    card::Card,
+    hand_ranking::{HandCategory, HandRanking},
+    evaluator::{evaluate_with, five_card_combos, HandRules}
+};
+
+/// Deuce-to-seven low hand. Aces are always high and straights and flushes count against the hand,
+/// so 7-5-4-3-2 is the best hand. Better low hands compare greater.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeuceToSevenLow {
+    ranking: HandRanking
+}
+
+impl DeuceToSevenLow {
+    /// High hand ranking of the cards, lower is better
+    #[inline]
+    pub const fn ranking(&self) -> &HandRanking {
+        return &self.ranking;
+    }
+
+    #[inline]
+    pub fn category(&self) -> HandCategory {
+        return self.ranking.category();
+    }
+}
+
+impl Ord for DeuceToSevenLow {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        return other.ranking.cmp(&self.ranking);
+    }
+}
+
+impl PartialOrd for DeuceToSevenLow {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Display for DeuceToSevenLow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self.ranking {
+            HandRanking::HighCard(ranks) => write!(f, "{}", ranks.map(|rank| rank.to_string()).join("-")),
+            ranking                      => write!(f, "{}", ranking)
+        };
+    }
+}
+
+/// Best deuce-to-seven low out of 5 to 7 cards
+pub fn evaluate_deuce_to_seven(cards: &[Card]) -> DeuceToSevenLow {
+    return five_card_combos(cards)
+        .map(|five| DeuceToSevenLow { ranking: evaluate_with(&five, HandRules::DEUCE_TO_SEVEN) })
+        .max()
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{card::Rank, hand_ranking::{HandCategory, HandRanking}, notation::parse_cards};
+
+    use super::evaluate_deuce_to_seven;
+
+    #[test]
+    fn test_seven_five_is_best() {
+        let nuts = evaluate_deuce_to_seven(&parse_cards("7s5d4c3h2s").unwrap());
+        assert_eq!(nuts.to_string(), "7-5-4-3-2");
+
+        assert!(nuts > evaluate_deuce_to_seven(&parse_cards("7s6d4c3h2s").unwrap()));
+        assert!(nuts > evaluate_deuce_to_seven(&parse_cards("8s5d4c3h2s").unwrap()));
+    }
+
+    #[test]
+    fn test_ace_is_high() {
+        let wheel = evaluate_deuce_to_seven(&parse_cards("As5d4c3h2s").unwrap());
+        assert_eq!(wheel.ranking(), &HandRanking::HighCard([Rank::Ace, Rank::Five, Rank::Four, Rank::Three, Rank::Two]));
+        assert!(wheel < evaluate_deuce_to_seven(&parse_cards("KsQdJc9h8s").unwrap()));
+    }
+
+    #[test]
+    fn test_straights_and_flushes_count() {
+        let straight = evaluate_deuce_to_seven(&parse_cards("6s5d4c3h2s").unwrap());
+        assert_eq!(straight.category(), HandCategory::Straight);
+        assert!(straight < evaluate_deuce_to_seven(&parse_cards("AsAdKcQhJs").unwrap()));
+
+        let flush = evaluate_deuce_to_seven(&parse_cards("7h5h4h3h2h").unwrap());
+        assert_eq!(flush.category(), HandCategory::Flush);
+        assert!(flush < straight);
+    }
+
+    #[test]
+    fn test_best_of_seven() {
+        let low = evaluate_deuce_to_seven(&parse_cards("7h5h4h3h2hKsKd").unwrap());
+        assert_eq!(low.to_string(), "K-5-4-3-2");
+
+        let low = evaluate_deuce_to_seven(&parse_cards("8s6d5c4h3s2d").unwrap());
+        assert_eq!(low.to_string(), "8-5-4-3-2");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod ace_to_five;
+pub mod deuce_to_seven;
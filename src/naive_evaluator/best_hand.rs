@@ -0,0 +1,146 @@
+use std::fmt::Display;
+
+use super::{
+    card::{Card, Rank},
+    card_set::CardSet,
+    hand::Hand,
+    hand_ranking::HandRanking,
+    evaluator::{evaluate, five_card_combos, CARDS_IN_COMBO}
+};
+
+/// Best hand together with the five cards which make it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BestHand {
+    ranking: HandRanking,
+    /// Cards which make the combination go first, kickers go last
+    cards: [Card; CARDS_IN_COMBO],
+    made_cards_count: usize
+}
+
+impl BestHand {
+    #[inline]
+    pub const fn ranking(&self) -> HandRanking {
+        return self.ranking;
+    }
+
+    /// Five cards in display order: combination cards by group size and rank, then kickers in descending order.
+    /// Ace goes last in a five high straight.
+    #[inline]
+    pub const fn cards(&self) -> &[Card; CARDS_IN_COMBO] {
+        return &self.cards;
+    }
+
+    /// Cards which make the combination, e.g. both cards of a pair
+    #[inline]
+    pub fn made_cards(&self) -> &[Card] {
+        return &self.cards[0..self.made_cards_count];
+    }
+
+    /// Side cards which only break ties
+    #[inline]
+    pub fn kickers(&self) -> &[Card] {
+        return &self.cards[self.made_cards_count..];
+    }
+
+    #[inline]
+    pub fn is_kicker(&self, index: usize) -> bool {
+        return index >= self.made_cards_count;
+    }
+}
+
+impl Display for BestHand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for card in self.cards {
+            write!(f, "{}", card)?;
+        }
+
+        return write!(f, " ({})", self.ranking);
+    }
+}
+
+/// Picks best five cards out of 5, 6 or 7 cards
+pub fn evaluate_best_hand(cards: &[Card]) -> BestHand {
+    let (ranking, mut five) = five_card_combos(cards)
+        .map(|five| (evaluate(&five), five))
+        .reduce(|best, next| if next.0 > best.0 { next } else { best })
+        .unwrap();
+
+    let picked = five;
+    let rank_count = |rank: Rank| picked.iter().filter(|card| card.rank() == rank).count();
+    five.sort_unstable_by(|c1, c2| rank_count(c2.rank()).cmp(&rank_count(c1.rank())).then(c2.cmp(c1)));
+
+    if matches!(ranking, HandRanking::Straight(Rank::Five) | HandRanking::StraightFlush(Rank::Five)) {
+        five.rotate_left(1);
+    }
+
+    let made_cards_count = match ranking {
+        HandRanking::HighCard(_)    => 1,
+        HandRanking::Pair(_)        => 2,
+        HandRanking::ThreeOf(_)     => 3,
+        HandRanking::TwoPair(_)     |
+        HandRanking::FourOf(_)      => 4,
+        HandRanking::Straight(_)    |
+        HandRanking::Flush(_)       |
+        HandRanking::FullHouse(_)   |
+        HandRanking::StraightFlush(_) |
+        HandRanking::FiveOf(_)      => CARDS_IN_COMBO
+    };
+
+    return BestHand { ranking, cards: five, made_cards_count };
+}
+
+impl Hand {
+    #[inline]
+    pub fn best_hand(&self) -> BestHand {
+        return evaluate_best_hand(self.cards());
+    }
+}
+
+impl CardSet {
+    #[inline]
+    pub fn best_hand(&self) -> BestHand {
+        return evaluate_best_hand(&self.iter().collect::<Vec<_>>());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{hand::Hand, hand_ranking::HandRanking, evaluator::evaluate_five_cards, notation::parse_cards};
+
+    #[test]
+    fn test_flush_cards() {
+        let best = Hand::try_from("AsQs5h7s9s6s5d").unwrap().best_hand();
+        assert_eq!(best.cards().to_vec(), parse_cards("AsQs9s7s6s").unwrap());
+        assert!(best.kickers().is_empty());
+    }
+
+    #[test]
+    fn test_pair_kickers() {
+        let best = Hand::try_from("Kc9h9sQc6s4c2d").unwrap().best_hand();
+        assert_eq!(best.made_cards().to_vec(), parse_cards("9s9h").unwrap());
+        assert_eq!(best.kickers().to_vec(), parse_cards("KcQc6s").unwrap());
+        assert!(!best.is_kicker(1));
+        assert!(best.is_kicker(2));
+    }
+
+    #[test]
+    fn test_full_house_order() {
+        let best = Hand::try_from("Ah9d9s9h7d6s6d").unwrap().best_hand();
+        assert_eq!(best.cards().to_vec(), parse_cards("9s9h9d6s6d").unwrap());
+        assert!(matches!(best.ranking(), HandRanking::FullHouse(_)));
+    }
+
+    #[test]
+    fn test_wheel_order() {
+        let best = Hand::try_from("AsKd5h4c3d2cQs").unwrap().best_hand();
+        assert_eq!(best.cards().to_vec(), parse_cards("5h4c3d2cAs").unwrap());
+    }
+
+    #[test]
+    fn test_ranking_matches_evaluator() {
+        for value in ["7h6d5h4c4d3h2c", "AdKcJcTcQc9c2c", "2c3c4c5cAc9d2d", "AsAcQd5s4s3s2s", "9dTc9cAc9s9hKs"] {
+            let hand = Hand::try_from(value).unwrap();
+            assert_eq!(hand.best_hand().ranking(), evaluate_five_cards(&hand));
+        }
+    }
+}
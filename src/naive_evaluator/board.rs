@@ -0,0 +1,265 @@
+use std::fmt::Display;
+
+use super::{
+    card::{Card, ParseError, ParseErrorKind},
+    card_set::CardSet,
+    hand::{Hand, HAND_SIZE},
+    pocket::Pocket,
+    notation::parse_positioned_cards
+};
+
+/// Most community cards a board can have
+pub const MAX_BOARD_SIZE: usize = 5;
+
+/// Betting round of hold'em, named after the last dealt community cards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River
+}
+
+impl Street {
+    pub const ALL: [Street; 4] = [Street::Preflop, Street::Flop, Street::Turn, Street::River];
+
+    /// Number of community cards on the street
+    #[inline]
+    pub const fn board_size(self) -> usize {
+        return match self {
+            Street::Preflop => 0,
+            Street::Flop    => 3,
+            Street::Turn    => 4,
+            Street::River   => MAX_BOARD_SIZE,
+        };
+    }
+
+    /// Street with the given number of community cards
+    #[inline]
+    pub fn from_board_size(size: usize) -> Option<Street> {
+        return Street::ALL.into_iter().find(|street| street.board_size() == size);
+    }
+
+    #[inline]
+    pub fn next(self) -> Option<Street> {
+        return Street::ALL.get(self as usize + 1).copied();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardError {
+    /// Board can only have 0, 3, 4 or 5 cards
+    WrongSize(usize),
+    /// Card is used twice on the board or in the pocket
+    DuplicateCard(Card)
+}
+
+impl Display for BoardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            BoardError::WrongSize(size)     => write!(f, "board can't have {} cards", size),
+            BoardError::DuplicateCard(card) => write!(f, "card {} is used more than once", card),
+        };
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+/// Community cards of hold'em, always a complete street
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Board {
+    /// Only first `len` cards are dealt, in the order they were dealt
+    cards: [Card; MAX_BOARD_SIZE],
+    len: usize
+}
+
+impl Board {
+    /// Preflop board without cards
+    pub const EMPTY: Board = Board { cards: [Card::from_index(0); MAX_BOARD_SIZE], len: 0 };
+
+    #[inline]
+    pub fn new() -> Self {
+        return Self::EMPTY;
+    }
+
+    /// Cards in the order they were dealt
+    #[inline]
+    pub fn cards(&self) -> &[Card] {
+        return &self.cards[..self.len];
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        return self.len;
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    #[inline]
+    pub fn street(&self) -> Street {
+        return Street::from_board_size(self.len).unwrap();
+    }
+
+    #[inline]
+    pub fn card_set(&self) -> CardSet {
+        return CardSet::from(self.cards());
+    }
+
+    /// Adds cards of the next streets, e.g. three cards preflop or one card on the flop.
+    /// Adding no cards keeps the current street. Board is left unchanged on error.
+    pub fn add(&mut self, cards: &[Card]) -> Result<Street, BoardError> {
+        let size = self.len + cards.len();
+        let street = Street::from_board_size(size).ok_or(BoardError::WrongSize(size))?;
+        let mut used = self.card_set();
+
+        for card in cards {
+            if !used.insert(*card) {
+                return Err(BoardError::DuplicateCard(*card));
+            }
+        }
+
+        self.cards[self.len..size].copy_from_slice(cards);
+        self.len = size;
+        return Ok(street);
+    }
+
+    /// Pocket and board cards, fails if they share a card
+    pub fn with_pocket(&self, pocket: &Pocket) -> Result<CardSet, BoardError> {
+        let mut cards = self.card_set();
+
+        for card in pocket.cards() {
+            if !cards.insert(*card) {
+                return Err(BoardError::DuplicateCard(*card));
+            }
+        }
+
+        return Ok(cards);
+    }
+
+    /// Seven card hand of the player on the river, fails if pocket and board share a card
+    pub fn hand(&self, pocket: &Pocket) -> Result<Hand, BoardError> {
+        if self.len != MAX_BOARD_SIZE {
+            return Err(BoardError::WrongSize(self.len));
+        }
+
+        let cards = self.with_pocket(pocket)?;
+        debug_assert_eq!(cards.len(), HAND_SIZE);
+        return Ok(Hand::try_from(cards).unwrap());
+    }
+}
+
+impl Default for Board {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl TryFrom<&[Card]> for Board {
+    type Error = BoardError;
+
+    #[inline]
+    fn try_from(value: &[Card]) -> Result<Self, Self::Error> {
+        let mut board = Board::new();
+        board.add(value)?;
+        return Ok(board);
+    }
+}
+
+impl TryFrom<&str> for Board {
+    type Error = ParseError;
+
+    /// 0, 3, 4 or 5 distinct cards in any notation supported by [`parse_cards`](super::notation::parse_cards),
+    /// e.g. `Ah7d2c`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parsed = parse_positioned_cards(value)?;
+        let chars: Vec<_> = value.chars().collect();
+
+        if Street::from_board_size(parsed.len()).is_none() {
+            let position = parsed.get(MAX_BOARD_SIZE).map_or(chars.len(), |(position, _)| *position);
+            return Err(ParseError::new(ParseErrorKind::WrongLength, position, chars.get(position).copied()));
+        }
+
+        let cards: Vec<_> = parsed.iter().map(|(_, card)| *card).collect();
+        let mut board = Board::new();
+
+        return match board.add(&cards) {
+            Ok(_) => Ok(board),
+            Err(BoardError::DuplicateCard(card)) => {
+                // Second occurrence is the one which repeats a card
+                let position = parsed.iter().filter(|(_, parsed)| *parsed == card).nth(1).unwrap().0;
+                Err(ParseError::new(ParseErrorKind::DuplicateCard, position, Some(chars[position])))
+            },
+            Err(BoardError::WrongSize(_)) => unreachable!("board size is checked above")
+        };
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for card in self.cards() {
+            write!(f, "{}", card)?;
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{
+        card::{ParseError, ParseErrorKind},
+        fixtures::{card, pocket},
+        hand::Hand
+    };
+
+    use super::{Board, BoardError, Street};
+
+    #[test]
+    fn test_streets() {
+        let mut board = Board::new();
+        assert_eq!(board.street(), Street::Preflop);
+
+        assert_eq!(board.add(&[card("Ah"), card("7d"), card("2c")]), Ok(Street::Flop));
+        assert_eq!(board.add(&[card("Ks")]), Ok(Street::Turn));
+        assert_eq!(board.add(&[card("Ks")]), Err(BoardError::DuplicateCard(card("Ks"))));
+        assert_eq!(board.add(&[card("Qs"), card("Js")]), Err(BoardError::WrongSize(6)));
+        assert_eq!(board.street(), Street::Turn);
+        assert_eq!(board.add(&[]), Ok(Street::Turn));
+        assert_eq!(board.add(&[card("Qs")]), Ok(Street::River));
+        assert_eq!(board.to_string(), "Ah7d2cKsQs");
+
+        assert_eq!(Board::new().add(&[card("Ah")]), Err(BoardError::WrongSize(1)));
+        assert_eq!(Board::new().add(&[]), Ok(Street::Preflop));
+    }
+
+    #[test]
+    fn test_parse() {
+        let board = Board::try_from("Ah7d2c").unwrap();
+        assert_eq!(board.street(), Street::Flop);
+        assert_eq!(board.cards(), &[card("Ah"), card("7d"), card("2c")]);
+
+        assert_eq!(Board::try_from("").unwrap().street(), Street::Preflop);
+        assert_eq!(Board::try_from("[A♥ 7♦ 2♣ K♠]").unwrap().street(), Street::Turn);
+        assert_eq!(Board::try_from("Ah7d"), Err(ParseError::new(ParseErrorKind::WrongLength, 4, None)));
+        assert_eq!(Board::try_from("Ah7d2cKsQsJs"), Err(ParseError::new(ParseErrorKind::WrongLength, 10, Some('J'))));
+        assert_eq!(Board::try_from("Ah7dAh"), Err(ParseError::new(ParseErrorKind::DuplicateCard, 4, Some('A'))));
+        assert_eq!(Board::try_from(&[card("Ah")][..]), Err(BoardError::WrongSize(1)));
+    }
+
+    #[test]
+    fn test_combine_with_pocket() {
+        let board = Board::try_from("Ah7d2cKsQs").unwrap();
+        let ace_king = pocket("AsKd");
+
+        assert_eq!(board.hand(&ace_king), Ok(Hand::try_from("AhAs7d2cKsKdQs").unwrap()));
+        assert_eq!(board.hand(&pocket("AsKs")), Err(BoardError::DuplicateCard(card("Ks"))));
+
+        let flop = Board::try_from("Ah7d2c").unwrap();
+        assert_eq!(flop.hand(&ace_king), Err(BoardError::WrongSize(3)));
+        assert_eq!(flop.with_pocket(&ace_king).map(|cards| cards.len()), Ok(5));
+    }
+}
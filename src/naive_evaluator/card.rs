@@ -1,7 +1,80 @@
 use std::fmt::Display;
 
+use super::notation::parse_card;
+
+/// Why a card notation can't be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    UnknownRank,
+    UnknownSuit,
+    /// Input has too few or too many characters
+    WrongLength,
+    /// Same card appears twice
+    DuplicateCard,
+    /// `[` without a closing `]` at the end of the input or `]` without an opening `[`
+    UnbalancedBracket
+}
+
+/// Error of parsing cards, ranks and suits from text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    position: usize,
+    character: Option<char>
+}
+
+impl ParseError {
+    /// `position` - index of the offending character in the input, counted in characters.
+    /// `character` - `None` when the input ended too early.
+    #[inline]
+    pub const fn new(kind: ParseErrorKind, position: usize, character: Option<char>) -> Self {
+        return Self { kind, position, character };
+    }
+
+    #[inline]
+    pub const fn kind(&self) -> ParseErrorKind {
+        return self.kind;
+    }
+
+    /// Index of the offending character in the input, counted in characters
+    #[inline]
+    pub const fn position(&self) -> usize {
+        return self.position;
+    }
+
+    #[inline]
+    pub const fn character(&self) -> Option<char> {
+        return self.character;
+    }
+
+    /// Same error for input which is a part of a longer input starting at `offset`
+    #[inline]
+    pub const fn offset(self, offset: usize) -> Self {
+        return Self::new(self.kind, self.position + offset, self.character);
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self.kind {
+            ParseErrorKind::UnknownRank         => "unknown rank",
+            ParseErrorKind::UnknownSuit         => "unknown suit",
+            ParseErrorKind::WrongLength         => "wrong length",
+            ParseErrorKind::DuplicateCard       => "duplicate card",
+            ParseErrorKind::UnbalancedBracket   => "unbalanced bracket",
+        };
+
+        return match self.character {
+            Some(character) => write!(f, "{} '{}' at position {}", reason, character, self.position),
+            None            => write!(f, "{}, input ends at position {}", reason, self.position),
+        };
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Suit {
     Clubs,
     Diamonds,
@@ -10,16 +83,31 @@ pub enum Suit {
 }
 
 impl TryFrom<char> for Suit {
-    type Error = ();
+    type Error = ParseError;
 
     #[inline]
     fn try_from(value: char) -> Result<Self, Self::Error> {
         return match value {
-            'c' => Ok(Suit::Clubs),
-            'd' => Ok(Suit::Diamonds),
-            'h' => Ok(Suit::Hearts),
-            's' => Ok(Suit::Spades),
-            _  => Err(()) 
+            'c' | '♣' | '♧' => Ok(Suit::Clubs),
+            'd' | '♦' | '♢' => Ok(Suit::Diamonds),
+            'h' | '♥' | '♡' => Ok(Suit::Hearts),
+            's' | '♠' | '♤' => Ok(Suit::Spades),
+            _  => Err(ParseError::new(ParseErrorKind::UnknownSuit, 0, Some(value)))
+        };
+    }
+}
+
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+    /// Unicode suit symbol, e.g. `♠`
+    #[inline]
+    pub const fn symbol(&self) -> char {
+        return match self {
+            Suit::Clubs     => '♣',
+            Suit::Diamonds  => '♦',
+            Suit::Hearts    => '♥',
+            Suit::Spades    => '♠',
         };
     }
 }
@@ -36,7 +124,7 @@ impl From<Suit> for char {
 }
 
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Rank {
     Two,
     Three,
@@ -54,11 +142,12 @@ pub enum Rank {
 }
 
 impl TryFrom<char> for Rank {
-    type Error = ();
+    type Error = ParseError;
 
+    /// Accepts lowercase letters too
     #[inline]
     fn try_from(value: char) -> Result<Self, Self::Error> {
-        return match value {
+        return match value.to_ascii_uppercase() {
             '2' => Ok(Rank::Two),
             '3' => Ok(Rank::Three),
             '4' => Ok(Rank::Four),
@@ -72,7 +161,7 @@ impl TryFrom<char> for Rank {
             'Q' => Ok(Rank::Queen),
             'K' => Ok(Rank::King),
             'A' => Ok(Rank::Ace),
-            _ => Err(())
+            _ => Err(ParseError::new(ParseErrorKind::UnknownRank, 0, Some(value)))
         };
     }
 }
@@ -105,16 +194,32 @@ impl Display for Rank {
 }
 
 impl Rank {
+    /// All ranks in ascending order
+    pub const ALL: [Rank; 13] = [
+        Rank::Two, Rank::Three, Rank::Four, Rank::Five,
+        Rank::Six, Rank::Seven, Rank::Eight, Rank::Nine,
+        Rank::Ten, Rank::Jack, Rank::Queen, Rank::King,
+        Rank::Ace
+    ];
+
     pub fn lower(&self) -> Option<Self> {
         if self != &Rank::Two {
-            unsafe { return Some(std::mem::transmute(*self as u8 - 1)); }
+            unsafe { return Some(std::mem::transmute::<u8, Rank>(*self as u8 - 1)); }
+        } else {
+            return None;
+        }
+    }
+
+    pub fn higher(&self) -> Option<Self> {
+        if self != &Rank::Ace {
+            unsafe { return Some(std::mem::transmute::<u8, Rank>(*self as u8 + 1)); }
         } else {
             return None;
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Card {
     rank: Rank,
     suit: Suit
@@ -138,6 +243,18 @@ impl Card {
     pub const fn suit(&self) -> Suit {
         return self.suit;
     }
+
+    /// Position of the card in `0..52`, ordered the same way as cards themselves
+    #[inline]
+    pub const fn index(&self) -> usize {
+        return self.rank as usize * Suit::ALL.len() + self.suit as usize;
+    }
+
+    /// Inverse of [`Card::index`]
+    #[inline]
+    pub const fn from_index(index: usize) -> Self {
+        return Self::new(Rank::ALL[index / Suit::ALL.len()], Suit::ALL[index % Suit::ALL.len()]);
+    }
 }
 
 impl From<&Card> for String {
@@ -148,17 +265,18 @@ impl From<&Card> for String {
 }
 
 impl TryFrom<&str> for Card {
-    type Error = ();
+    type Error = ParseError;
 
-    #[inline]
+    /// Rank and suit like `As`, `td`, `10h` or `K♠`
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.len() != 2 {
-            return Err(());
+        let chars: Vec<_> = value.chars().collect();
+        let (card, end) = parse_card(&chars, 0)?;
+
+        if let Some(extra) = chars.get(end) {
+            return Err(ParseError::new(ParseErrorKind::WrongLength, end, Some(*extra)));
         }
 
-        let rank = Rank::try_from(value.chars().nth(0).unwrap())?;
-        let suit = Suit::try_from(value.chars().nth(1).unwrap())?;
-        return Ok(Card::new(rank, suit));
+        return Ok(card);
     }
 }
 
@@ -168,3 +286,37 @@ impl Display for Card {
         return write!(f, "{}", String::from(self));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::card::{Card, ParseError, ParseErrorKind, Rank, Suit};
+
+    #[test]
+    fn test_parse_card() {
+        assert_eq!(Card::try_from("As"), Ok(Card::new(Rank::Ace, Suit::Spades)));
+        assert_eq!(Card::try_from("Xs"), Err(ParseError::new(ParseErrorKind::UnknownRank, 0, Some('X'))));
+        assert_eq!(Card::try_from("Kx"), Err(ParseError::new(ParseErrorKind::UnknownSuit, 1, Some('x'))));
+        assert_eq!(Card::try_from("K"), Err(ParseError::new(ParseErrorKind::WrongLength, 1, None)));
+        assert_eq!(Card::try_from("Kss"), Err(ParseError::new(ParseErrorKind::WrongLength, 2, Some('s'))));
+        assert_eq!(Rank::try_from('1'), Err(ParseError::new(ParseErrorKind::UnknownRank, 0, Some('1'))));
+    }
+
+    #[test]
+    fn test_parse_alternative_notations() {
+        let ten = Card::new(Rank::Ten, Suit::Hearts);
+        assert_eq!(Card::try_from("Th"), Ok(ten));
+        assert_eq!(Card::try_from("th"), Ok(ten));
+        assert_eq!(Card::try_from("10h"), Ok(ten));
+        assert_eq!(Card::try_from("10♥"), Ok(ten));
+        assert_eq!(Card::try_from("a♠"), Ok(Card::new(Rank::Ace, Suit::Spades)));
+        assert_eq!(Card::try_from("1h"), Err(ParseError::new(ParseErrorKind::UnknownRank, 0, Some('1'))));
+        assert_eq!(Card::try_from("10"), Err(ParseError::new(ParseErrorKind::WrongLength, 2, None)));
+        assert_eq!(Card::try_from("K★"), Err(ParseError::new(ParseErrorKind::UnknownSuit, 1, Some('★'))));
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        assert_eq!(Card::try_from("Kx").unwrap_err().to_string(), "unknown suit 'x' at position 1");
+        assert_eq!(Card::try_from("").unwrap_err().to_string(), "wrong length, input ends at position 0");
+    }
+}
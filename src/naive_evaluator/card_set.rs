@@ -0,0 +1,316 @@
+use std::{fmt::Display, ops::{BitOr, BitAnd, Sub, BitOrAssign, BitAndAssign, SubAssign}};
+
+use super::{card::Card, deck::Deck, hand::{Hand, HAND_SIZE}};
+
+/// Set of cards, one bit per card at [`Card::index`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    pub const EMPTY: CardSet = CardSet(0);
+    pub const FULL: CardSet = CardSet((1 << Deck::SIZE) - 1);
+
+    #[inline]
+    pub const fn new() -> Self {
+        return Self::EMPTY;
+    }
+
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        return Self(bits & Self::FULL.0);
+    }
+
+    #[inline]
+    pub const fn bits(&self) -> u64 {
+        return self.0;
+    }
+
+    /// Returns `true` if card was not in the set
+    #[inline]
+    pub fn insert(&mut self, card: Card) -> bool {
+        let inserted = !self.contains(card);
+        self.0 |= Self::bit(card);
+        return inserted;
+    }
+
+    /// Returns `true` if card was in the set
+    #[inline]
+    pub fn remove(&mut self, card: Card) -> bool {
+        let removed = self.contains(card);
+        self.0 &= !Self::bit(card);
+        return removed;
+    }
+
+    #[inline]
+    pub const fn contains(&self, card: Card) -> bool {
+        return self.0 & Self::bit(card) != 0;
+    }
+
+    #[inline]
+    pub const fn union(&self, other: CardSet) -> CardSet {
+        return CardSet(self.0 | other.0);
+    }
+
+    #[inline]
+    pub const fn intersection(&self, other: CardSet) -> CardSet {
+        return CardSet(self.0 & other.0);
+    }
+
+    #[inline]
+    pub const fn difference(&self, other: CardSet) -> CardSet {
+        return CardSet(self.0 & !other.0);
+    }
+
+    /// Cards which are not in the set
+    #[inline]
+    pub const fn complement(&self) -> CardSet {
+        return Self::FULL.difference(*self);
+    }
+
+    #[inline]
+    pub const fn is_disjoint(&self, other: CardSet) -> bool {
+        return self.0 & other.0 == 0;
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        return self.0.count_ones() as usize;
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        return self.0 == 0;
+    }
+
+    /// Iterates cards in ascending order
+    #[inline]
+    pub fn iter(&self) -> CardSetIter {
+        return CardSetIter(self.0);
+    }
+
+    #[inline]
+    const fn bit(card: Card) -> u64 {
+        return 1 << card.index();
+    }
+}
+
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let index = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        return Some(Card::from_index(index));
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        return (len, Some(len));
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<T: IntoIterator<Item = Card>>(iter: T) -> Self {
+        let mut set = CardSet::new();
+        set.extend(iter);
+        return set;
+    }
+}
+
+impl<'a> FromIterator<&'a Card> for CardSet {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = &'a Card>>(iter: T) -> Self {
+        return iter.into_iter().copied().collect();
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<T: IntoIterator<Item = Card>>(&mut self, iter: T) {
+        for card in iter {
+            self.insert(card);
+        }
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        return self.union(rhs);
+    }
+}
+
+impl BitOrAssign for CardSet {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        return self.intersection(rhs);
+    }
+}
+
+impl BitAndAssign for CardSet {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        return self.difference(rhs);
+    }
+}
+
+impl SubAssign for CardSet {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl From<Card> for CardSet {
+    #[inline]
+    fn from(value: Card) -> Self {
+        return CardSet(Self::bit(value));
+    }
+}
+
+impl From<&[Card]> for CardSet {
+    #[inline]
+    fn from(value: &[Card]) -> Self {
+        return value.iter().collect();
+    }
+}
+
+impl From<&Deck> for CardSet {
+    #[inline]
+    fn from(value: &Deck) -> Self {
+        return value.cards().iter().collect();
+    }
+}
+
+impl From<CardSet> for Deck {
+    /// Deck with cards of the set in ascending order
+    #[inline]
+    fn from(value: CardSet) -> Self {
+        return Deck::from_cards(value.iter().collect());
+    }
+}
+
+impl From<&Hand> for CardSet {
+    #[inline]
+    fn from(value: &Hand) -> Self {
+        return value.cards().iter().collect();
+    }
+}
+
+impl TryFrom<CardSet> for Hand {
+    type Error = ();
+
+    fn try_from(value: CardSet) -> Result<Self, Self::Error> {
+        if value.len() != HAND_SIZE {
+            return Err(());
+        }
+
+        let mut cards = value.iter();
+        return Ok(Hand::new([(); HAND_SIZE].map(|_| cards.next().unwrap())));
+    }
+}
+
+impl Display for CardSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for card in self.iter() {
+            write!(f, "{}", card)?;
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{card::{Card, Rank, Suit}, deck::Deck, hand::Hand};
+
+    use super::CardSet;
+
+    #[test]
+    fn test_insert_remove() {
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        let mut set = CardSet::new();
+
+        assert!(set.insert(ace));
+        assert!(!set.insert(ace));
+        assert!(set.contains(ace));
+        assert_eq!(set.len(), 1);
+
+        assert!(set.remove(ace));
+        assert!(!set.remove(ace));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: CardSet = [Card::new(Rank::Ace, Suit::Spades), Card::new(Rank::King, Suit::Spades)].iter().collect();
+        let b: CardSet = [Card::new(Rank::King, Suit::Spades), Card::new(Rank::Two, Suit::Clubs)].iter().collect();
+
+        assert_eq!((a | b).len(), 3);
+        assert_eq!(a & b, CardSet::from(Card::new(Rank::King, Suit::Spades)));
+        assert_eq!(a - b, CardSet::from(Card::new(Rank::Ace, Suit::Spades)));
+        assert_eq!(a.complement().len(), Deck::SIZE - 2);
+        assert!(!a.is_disjoint(b));
+        assert!((a - b).is_disjoint(b));
+    }
+
+    #[test]
+    fn test_iterates_in_rank_order() {
+        let set = CardSet::from(&Deck::new().shuffled());
+        assert_eq!(set, CardSet::FULL);
+
+        let cards: Vec<_> = set.iter().collect();
+        assert_eq!(cards.len(), Deck::SIZE);
+        assert!(cards.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(cards[0], Card::new(Rank::Two, Suit::Clubs));
+        assert_eq!(cards[Deck::SIZE - 1], Card::new(Rank::Ace, Suit::Spades));
+    }
+
+    #[test]
+    fn test_hand_conversion() {
+        let hand = Hand::try_from("AsAcQd5s4s3s2s").unwrap();
+        let set = CardSet::from(&hand);
+        assert_eq!(set.len(), 7);
+        assert_eq!(Hand::try_from(set), Ok(hand));
+        assert_eq!(Hand::try_from(set - CardSet::from(Card::new(Rank::Ace, Suit::Spades))), Err(()));
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt::Display;
+
+use super::{board::{Board, BoardError, Street}, card::Card, deck::Deck, pocket::Pocket};
+
+/// Number of community cards dealt on the flop
+pub const FLOP_SIZE: usize = Street::Flop.board_size();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DealError {
+    /// Deck doesn't have enough cards, nothing was dealt
+    NotEnoughCards,
+    /// Street is dealt before the previous one or twice
+    OutOfOrder,
+    /// Deck would put the same card on the board twice, nothing was dealt
+    DuplicateCard(Card)
+}
+
+impl Display for DealError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            DealError::NotEnoughCards       => write!(f, "not enough cards left in the deck"),
+            DealError::OutOfOrder           => write!(f, "street is dealt out of order"),
+            DealError::DuplicateCard(card)  => write!(f, "card {} is dealt to the board twice", card),
+        };
+    }
+}
+
+impl std::error::Error for DealError {}
+
+/// Deals hold'em pockets and community cards from a deck
+#[derive(Debug, Clone)]
+pub struct Dealer {
+    deck: Deck,
+    board: Board,
+    burned: Vec<Card>
+}
+
+impl Dealer {
+    /// Deals from the top of the deck as is, shuffle it beforehand
+    #[inline]
+    pub fn new(deck: Deck) -> Self {
+        return Self { deck, board: Board::new(), burned: Vec::with_capacity(3) };
+    }
+
+    #[inline]
+    pub fn deck(&self) -> &Deck {
+        return &self.deck;
+    }
+
+    /// Community cards dealt so far
+    #[inline]
+    pub fn board(&self) -> &Board {
+        return &self.board;
+    }
+
+    #[inline]
+    pub fn burned(&self) -> &[Card] {
+        return &self.burned;
+    }
+
+    /// Deals one card to each player in turn, then the second card the same way
+    pub fn deal_pockets(&mut self, players: usize) -> Result<Vec<Pocket>, DealError> {
+        let cards = self.draw(2 * players)?;
+        return Ok((0..players).map(|player| Pocket::new(cards[player], cards[players + player])).collect());
+    }
+
+    /// Burns a card and deals three community cards
+    #[inline]
+    pub fn deal_flop(&mut self) -> Result<[Card; FLOP_SIZE], DealError> {
+        let flop = self.deal_street(Street::Flop)?;
+        return Ok(flop.try_into().unwrap());
+    }
+
+    /// Burns a card and deals the fourth community card
+    #[inline]
+    pub fn deal_turn(&mut self) -> Result<Card, DealError> {
+        return Ok(self.deal_street(Street::Turn)?[0]);
+    }
+
+    /// Burns a card and deals the fifth community card
+    #[inline]
+    pub fn deal_river(&mut self) -> Result<Card, DealError> {
+        return Ok(self.deal_street(Street::River)?[0]);
+    }
+
+    /// Burns a card and deals cards of the street, which must follow the current one
+    fn deal_street(&mut self, street: Street) -> Result<&[Card], DealError> {
+        let dealt = self.board.len();
+
+        if self.board.street().next() != Some(street) {
+            return Err(DealError::OutOfOrder);
+        }
+
+        let count = street.board_size() - dealt + 1;
+        let deck = self.deck.cards();
+
+        if deck.len() < count {
+            return Err(DealError::NotEnoughCards);
+        }
+
+        // Top of the deck is at the end, the burn card goes first
+        let street_cards: Vec<_> = deck[deck.len() - count..deck.len() - 1].iter().rev().copied().collect();
+        let mut board = self.board;
+        board.add(&street_cards).map_err(|error| match error {
+            BoardError::DuplicateCard(card) => DealError::DuplicateCard(card),
+            BoardError::WrongSize(_)        => unreachable!("street has the right number of cards")
+        })?;
+
+        let mut cards = self.draw(count)?;
+        self.burned.push(cards.remove(0));
+        self.board = board;
+        return Ok(&self.board.cards()[dealt..]);
+    }
+
+    /// Draws `count` cards or nothing if the deck has fewer cards
+    fn draw(&mut self, count: usize) -> Result<Vec<Card>, DealError> {
+        if self.deck.cards().len() < count {
+            return Err(DealError::NotEnoughCards);
+        }
+
+        return Ok((0..count).map(|_| self.deck.draw_card().unwrap()).collect());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{board::Street, deck::Deck, fixtures::{card, deck, pocket}};
+
+    use super::{Dealer, DealError};
+
+    #[test]
+    fn test_deals_hand() {
+        let mut dealer = Dealer::new(deck("AsKsQs JsTs9s 2c 3c4c5c 2d 6c 2h 7c"));
+
+        assert_eq!(
+            dealer.deal_pockets(3),
+            Ok(vec![pocket("AsJs"), pocket("KsTs"), pocket("Qs9s")])
+        );
+        assert_eq!(dealer.deal_flop(), Ok([card("3c"), card("4c"), card("5c")]));
+        assert_eq!(dealer.deal_turn(), Ok(card("6c")));
+        assert_eq!(dealer.deal_river(), Ok(card("7c")));
+
+        assert_eq!(dealer.board().cards(), &["3c", "4c", "5c", "6c", "7c"].map(card));
+        assert_eq!(dealer.board().street(), Street::River);
+        assert_eq!(dealer.burned(), &["2c", "2d", "2h"].map(card));
+        assert!(dealer.deck().cards().is_empty());
+    }
+
+    #[test]
+    fn test_out_of_order() {
+        let mut dealer = Dealer::new(Deck::new());
+        assert_eq!(dealer.deal_turn(), Err(DealError::OutOfOrder));
+        assert_eq!(dealer.deal_river(), Err(DealError::OutOfOrder));
+
+        dealer.deal_flop().unwrap();
+        assert_eq!(dealer.deal_flop(), Err(DealError::OutOfOrder));
+        assert_eq!(dealer.deal_river(), Err(DealError::OutOfOrder));
+        assert_eq!(dealer.deck().cards().len(), Deck::SIZE - 4);
+    }
+
+    #[test]
+    fn test_not_enough_cards() {
+        let mut dealer = Dealer::new(Deck::new());
+        assert_eq!(dealer.deal_pockets(27), Err(DealError::NotEnoughCards));
+        assert_eq!(dealer.deck().cards().len(), Deck::SIZE);
+
+        dealer.deal_pockets(23).unwrap();
+        dealer.deal_flop().unwrap();
+        dealer.deal_turn().unwrap();
+        assert_eq!(dealer.deal_river(), Err(DealError::NotEnoughCards));
+        assert_eq!(dealer.board().len(), 4);
+        assert!(dealer.deck().cards().is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_card() {
+        let mut dealer = Dealer::new(deck("2c 3c4c5c 2d 3c"));
+        dealer.deal_flop().unwrap();
+        assert_eq!(dealer.deal_turn(), Err(DealError::DuplicateCard(card("3c"))));
+        assert_eq!(dealer.deck().cards().len(), 2);
+        assert_eq!(dealer.burned(), &[card("2c")]);
+        assert_eq!(dealer.board().len(), 3);
+
+        let mut dealer = Dealer::new(deck("2c 3c4c3c"));
+        assert_eq!(dealer.deal_flop(), Err(DealError::DuplicateCard(card("3c"))));
+        assert_eq!(dealer.deck().cards().len(), 4);
+        assert!(dealer.burned().is_empty());
+    }
+}
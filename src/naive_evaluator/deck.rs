@@ -1,18 +1,37 @@
-use rand::Rng;
+use std::fmt::Display;
 
-use super::card::{Card, Suit, Rank};
+use rand::{Rng, SeedableRng, rngs::StdRng};
 
+use super::{card::{Card, Suit, Rank}, card_set::CardSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeckError {
+    CardNotInDeck(Card)
+}
+
+impl Display for DeckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            DeckError::CardNotInDeck(card) => write!(f, "card {} is not in the deck", card),
+        };
+    }
+}
+
+impl std::error::Error for DeckError {}
+
+/// Cards to deal from, standard cards unless given another card type, e.g. with jokers
 #[derive(Debug, Clone)]
-pub struct Deck {
-    cards: Vec<Card>
+pub struct Deck<C = Card> {
+    cards: Vec<C>
 }
 
 impl Deck {
     pub const SIZE: usize = 52;
+    /// Short deck has no 2s to 5s
+    pub const SHORT_SIZE: usize = 36;
 
     pub fn new() -> Self {
-        let mut cards = Vec::new();
-        cards.reserve(Self::SIZE);
+        let mut cards = Vec::with_capacity(Self::SIZE);
 
         for suit in [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades] {
             for rank in [
@@ -28,6 +47,57 @@ impl Deck {
         return Self { cards };
     }
 
+    /// 36 card deck for short deck hold'em, ranks from Six to Ace
+    pub fn new_short() -> Self {
+        let mut cards = Vec::with_capacity(Self::SHORT_SIZE);
+
+        for suit in Suit::ALL {
+            for rank in Rank::ALL.into_iter().filter(|rank| *rank >= Rank::Six) {
+                cards.push(Card::new(rank, suit));
+            }
+        }
+
+        return Self { cards };
+    }
+
+    /// Full deck without given cards, e.g. known pockets and board. Cards not in the deck are ignored.
+    #[inline]
+    pub fn without(cards: &[Card]) -> Self {
+        return Self::without_set(CardSet::from(cards));
+    }
+
+    /// Full deck without cards of the set, in the same order as [`Deck::new`]
+    pub fn without_set(cards: CardSet) -> Self {
+        let mut deck = Self::new();
+        deck.cards.retain(|card| !cards.contains(*card));
+        return deck;
+    }
+
+    /// Deck shuffled with a generator seeded by `seed`, same seed always gives the same order
+    #[inline]
+    pub fn seeded(seed: u64) -> Self {
+        return Self::new().shuffled_with(&mut StdRng::seed_from_u64(seed));
+    }
+
+    /// Takes the card out of the deck keeping order of other cards, fails if the card is not in the deck
+    pub fn remove(&mut self, card: Card) -> Result<(), DeckError> {
+        let position = self.cards
+            .iter()
+            .position(|other| *other == card)
+            .ok_or(DeckError::CardNotInDeck(card))?;
+
+        self.cards.remove(position);
+        return Ok(());
+    }
+}
+
+impl<C> Deck<C> {
+    /// Deck with given cards, the last card is drawn first
+    #[inline]
+    pub fn from_cards(cards: Vec<C>) -> Self {
+        return Self { cards };
+    }
+
     #[inline]
     pub fn shuffled(mut self) -> Self {
         self.shuffle();
@@ -35,28 +105,167 @@ impl Deck {
     }
 
     #[inline]
-    pub fn cards(&self) -> &Vec<Card> {
+    pub fn shuffled_with(mut self, rng: &mut impl Rng) -> Self {
+        self.shuffle_with(rng);
+        return self;
+    }
+
+    #[inline]
+    pub fn cards(&self) -> &Vec<C> {
         return &self.cards;
     }
 
     #[inline]
-    pub fn draw_card(&mut self) -> Option<Card> {
+    pub fn draw_card(&mut self) -> Option<C> {
         return self.cards.pop();
     }
 
     #[inline]
-    pub fn put_card(&mut self, card: Card) {
+    pub fn put_card(&mut self, card: C) {
         self.cards.push(card);
     }
 
+    #[inline]
     pub fn shuffle(&mut self) -> &mut Self {
-        let mut rng = rand::thread_rng();
+        return self.shuffle_with(&mut rand::thread_rng());
+    }
 
-        for i in 0..Self::SIZE - 2 {
-            let j = rng.gen_range(i..Self::SIZE);
+    /// Fisher-Yates shuffle of the cards left in the deck
+    pub fn shuffle_with(&mut self, rng: &mut impl Rng) -> &mut Self {
+        for i in (1..self.cards.len()).rev() {
+            let j = rng.gen_range(0..=i);
             self.cards.swap(i, j);
         }
 
         return self;
     }
 }
+
+impl Default for Deck {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng, rngs::StdRng};
+
+    use crate::naive_evaluator::{card::Card, deck::{Deck, DeckError}};
+
+    /// Pearson's chi-square statistic of the card/position table over `trials` shuffles,
+    /// together with the degrees of freedom
+    fn chi_square(deck: &Deck, trials: usize, shuffle: impl Fn(&mut Deck, &mut StdRng)) -> (f64, f64) {
+        let size = deck.cards().len();
+        let mut rng = StdRng::seed_from_u64(2024);
+        let mut counts = vec![vec![0usize; size]; size];
+
+        for _ in 0..trials {
+            let mut shuffled = deck.clone();
+            shuffle(&mut shuffled, &mut rng);
+
+            for (position, card) in shuffled.cards().iter().enumerate() {
+                let original = deck.cards().iter().position(|other| other == card).unwrap();
+                counts[position][original] += 1;
+            }
+        }
+
+        let expected = trials as f64 / size as f64;
+        let statistic = counts.iter()
+            .flatten()
+            .map(|count| (*count as f64 - expected).powi(2) / expected)
+            .sum();
+        let freedom = ((size - 1) * (size - 1)) as f64;
+
+        return (statistic, freedom);
+    }
+
+    /// Statistic is within 5 standard deviations of its mean
+    fn is_uniform((statistic, freedom): (f64, f64)) -> bool {
+        return statistic < freedom + 5.0 * (2.0 * freedom).sqrt();
+    }
+
+    #[test]
+    fn test_shuffle_is_uniform() {
+        let statistic = chi_square(&Deck::new(), 20_000, |deck, rng| { deck.shuffle_with(rng); });
+        assert!(is_uniform(statistic), "{:?}", statistic);
+    }
+
+    #[test]
+    fn test_shuffle_after_drawing_is_uniform() {
+        let mut deck = Deck::new();
+
+        for _ in 0..40 {
+            deck.draw_card();
+        }
+
+        let statistic = chi_square(&deck, 20_000, |deck, rng| { deck.shuffle_with(rng); });
+        assert!(is_uniform(statistic), "{:?}", statistic);
+
+        let mut deck = Deck::from_cards(vec![Card::try_from("As").unwrap()]);
+        deck.shuffle();
+        assert_eq!(deck.cards().len(), 1);
+        Deck::<Card>::from_cards(Vec::new()).shuffle();
+    }
+
+    #[test]
+    fn test_detects_biased_shuffle() {
+        // Skips the final swap
+        let statistic = chi_square(&Deck::new(), 20_000, |deck, rng| {
+            let mut cards = deck.cards().clone();
+
+            for i in 0..cards.len() - 2 {
+                let j = rng.gen_range(i..cards.len());
+                cards.swap(i, j);
+            }
+
+            *deck = Deck::from_cards(cards);
+        });
+        assert!(!is_uniform(statistic), "{:?}", statistic);
+    }
+
+    #[test]
+    fn test_without_cards() {
+        let known = ["As", "Kd", "7c"].map(|card| Card::try_from(card).unwrap());
+        let deck = Deck::without(&known);
+
+        assert_eq!(deck.cards().len(), Deck::SIZE - 3);
+        assert!(known.iter().all(|card| !deck.cards().contains(card)));
+
+        let mut expected = Deck::new().cards().clone();
+        expected.retain(|card| !known.contains(card));
+        assert_eq!(deck.cards(), &expected);
+
+        assert_eq!(Deck::without(&[]).cards(), Deck::new().cards());
+    }
+
+    #[test]
+    fn test_remove() {
+        let ace = Card::try_from("As").unwrap();
+        let mut deck = Deck::new();
+
+        assert_eq!(deck.remove(ace), Ok(()));
+        assert_eq!(deck.cards().len(), Deck::SIZE - 1);
+        assert_eq!(deck.remove(ace), Err(DeckError::CardNotInDeck(ace)));
+
+        deck.put_card(ace);
+        assert_eq!(deck.draw_card(), Some(ace));
+        assert_eq!(deck.cards(), Deck::without(&[ace]).cards());
+    }
+
+    #[test]
+    fn test_seeded_shuffle_is_reproducible() {
+        assert_eq!(Deck::seeded(42).cards(), Deck::seeded(42).cards());
+        assert_ne!(Deck::seeded(42).cards(), Deck::seeded(43).cards());
+        assert_ne!(Deck::seeded(42).cards(), Deck::new().cards());
+
+        let mut rng1 = StdRng::seed_from_u64(7);
+        let mut rng2 = StdRng::seed_from_u64(7);
+        let mut deck1 = Deck::new();
+        let mut deck2 = Deck::new();
+        deck1.shuffle_with(&mut rng1).shuffle_with(&mut rng1);
+        deck2.shuffle_with(&mut rng2).shuffle_with(&mut rng2);
+        assert_eq!(deck1.cards(), deck2.cards());
+    }
+}
@@ -1,75 +1,204 @@
 use super::{
     hand::{Hand, HAND_SIZE},
-    hand_ranking::{HandRanking, FourOf, FullHouse, Flush, ThreeOf, TwoPair, Pair}, 
-    card::{Rank, Suit}
+    hand_ranking::{HandRanking, HandCategory, PartialHandRanking, FourOf, FullHouse, Flush, ThreeOf, TwoPair, Pair}, 
+    card::{Card, Rank, Suit},
+    card_set::CardSet
 };
 
 pub const CARDS_IN_COMBO: usize = 5;
 
+/// Lowest straight, where Ace plays as the lowest card
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LowStraight {
+    /// A-2-3-4-5
+    Wheel,
+    /// A-6-7-8-9, deck has no 2s to 5s
+    ShortDeck,
+    /// Ace always plays high, A-2-3-4-5 is not a straight (deuce-to-seven lowball)
+    AceHigh
+}
+
+/// Rules which differ between poker variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandRules {
+    pub low_straight: LowStraight,
+    pub flush_beats_full_house: bool,
+    pub trips_beat_straight: bool
+}
+
+impl HandRules {
+    pub const HOLDEM: HandRules = HandRules {
+        low_straight: LowStraight::Wheel,
+        flush_beats_full_house: false,
+        trips_beat_straight: false
+    };
+
+    pub const DEUCE_TO_SEVEN: HandRules = HandRules {
+        low_straight: LowStraight::AceHigh,
+        ..HandRules::HOLDEM
+    };
+}
+
+impl Default for HandRules {
+    #[inline]
+    fn default() -> Self {
+        return Self::HOLDEM;
+    }
+}
+
+/// Best five cards out of the 7-card hand
+#[inline]
 pub fn evaluate_five_cards(hand: &Hand) -> HandRanking {
-    return has_straight_flush(hand)
-        .or_else(|| has_four_of(hand))
-        .or_else(|| has_full_house(hand))
-        .or_else(|| has_flush(hand))
-        .or_else(|| has_straight(hand))
-        .or_else(|| has_three_of(hand))
-        .or_else(|| has_pairs(hand))
-        .unwrap_or(highest_card(hand));
+    return evaluate_sorted(hand.cards(), HandRules::HOLDEM);
 }
 
-fn has_straight_flush(hand: &Hand) -> Option<HandRanking> {
-    for first_card_idx in 0..HAND_SIZE - CARDS_IN_COMBO + 2 { // +2 -> straight flush with low Ace
-        let high_card = hand.cards()[first_card_idx];
-        let mut previous_card = high_card;
-        let mut straight_cards_count = 1;
+/// Best five cards out of 5, 6 or 7 cards in any order.
+/// On the flop, turn and river this is the made hand of a player.
+#[inline]
+pub fn evaluate(cards: &[Card]) -> HandRanking {
+    return evaluate_with(cards, HandRules::HOLDEM);
+}
 
-        for next_card_idx in first_card_idx..hand.cards().len() {
-            let next_card = hand.cards()[next_card_idx];
+/// Same as [`evaluate`], but picks the best category in the order of given rules
+pub fn evaluate_with(cards: &[Card], rules: HandRules) -> HandRanking {
+    assert_hand_size(cards.len());
 
-            if previous_card.rank() == next_card.rank() {
-                continue;
-            }
+    let mut sorted = [cards[0]; HAND_SIZE];
+    let sorted = &mut sorted[0..cards.len()];
+    sorted.copy_from_slice(cards);
+    sorted.sort_unstable_by(|c1, c2| c2.cmp(c1));
+    return evaluate_sorted(sorted, rules);
+}
 
-            let expected_rank =
-                if let Some(lower) = previous_card.rank().lower() {
-                    lower
-                } else {
-                    return None;
-                };
+/// Same as [`evaluate`] for 5, 6 or 7 cards in a set
+#[inline]
+pub fn evaluate_card_set(cards: CardSet) -> HandRanking {
+    let mut sorted = [Card::new(Rank::Two, Suit::Clubs); HAND_SIZE];
+    let count = cards.len();
+    assert_hand_size(count);
 
-            if expected_rank != next_card.rank() {
-                break;
-            }
+    // Set iterates in ascending order
+    for (i, card) in cards.iter().enumerate() {
+        sorted[count - 1 - i] = card;
+    }
 
-            if next_card.suit() != high_card.suit() {
-                // Next card can have same rank with required suit
-                continue;
-            }
-            
-            if next_card.rank() == Rank::Two {
-                let ace_low = hand.cards().iter()
-                    .take_while(|c| c.rank() == Rank::Ace)
-                    .filter(|c| c.suit() == high_card.suit());
-
-                if ace_low.count() != 0 {
-                    straight_cards_count += 1;
-                }
-            }
+    return evaluate_sorted(&sorted[0..count], HandRules::HOLDEM);
+}
 
-            previous_card = next_card;
-            straight_cards_count += 1;
-        }
+/// Panics unless there are 5 to 7 cards
+#[inline]
+pub(crate) fn assert_hand_size(count: usize) {
+    assert!(
+        (CARDS_IN_COMBO..=HAND_SIZE).contains(&count),
+        "expected {} to {} cards, got {}", CARDS_IN_COMBO, HAND_SIZE, count
+    );
+}
 
-        if straight_cards_count >= CARDS_IN_COMBO {
-            return Some(HandRanking::StraightFlush(high_card.rank()));
-        }
+/// Every five card combination out of 5 to 7 cards, cards of each combination keep their order
+pub(crate) fn five_card_combos<T: Copy>(cards: &[T]) -> impl Iterator<Item = [T; CARDS_IN_COMBO]> + '_ {
+    assert_hand_size(cards.len());
+
+    return (0u8..1 << cards.len())
+        .filter(|mask| mask.count_ones() as usize == CARDS_IN_COMBO)
+        .map(move |mask| {
+            let mut picked = cards.iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, card)| *card);
+            return [(); CARDS_IN_COMBO].map(|_| picked.next().unwrap());
+        });
+}
+
+/// Ranking of 1 to 4 cards, e.g. stud upcards. Straights and flushes don't count.
+pub fn evaluate_partial(cards: &[Card]) -> PartialHandRanking {
+    assert!((1..CARDS_IN_COMBO).contains(&cards.len()), "expected 1 to {} cards, got {}", CARDS_IN_COMBO - 1, cards.len());
+
+    let count = |rank: Rank| cards.iter().filter(|card| card.rank() == rank).count();
+    let mut ranks: Vec<_> = cards.iter().map(|card| (count(card.rank()), card.rank())).collect();
+    ranks.sort_unstable_by(|r1, r2| r2.cmp(r1));
+
+    let pairs = ranks.iter().filter(|(count, _)| *count == 2).count() / 2;
+    let category = match ranks[0].0 {
+        1               => HandCategory::HighCard,
+        2 if pairs == 1 => HandCategory::Pair,
+        2               => HandCategory::TwoPair,
+        3               => HandCategory::ThreeOf,
+        _               => HandCategory::FourOf
+    };
+
+    let ranks: Vec<_> = ranks.into_iter().map(|(_, rank)| rank).collect();
+    return PartialHandRanking::new(category, &ranks);
+}
+
+/// `cards` - 5 to 7 cards in descending order
+fn evaluate_sorted(cards: &[Card], rules: HandRules) -> HandRanking {
+    let flush_or_full_house = || if rules.flush_beats_full_house {
+        has_flush(cards).or_else(|| has_full_house(cards))
+    } else {
+        has_full_house(cards).or_else(|| has_flush(cards))
+    };
+    let straight_or_three_of = || if rules.trips_beat_straight {
+        has_three_of(cards).or_else(|| has_straight(cards, rules.low_straight))
+    } else {
+        has_straight(cards, rules.low_straight).or_else(|| has_three_of(cards))
+    };
+
+    return has_straight_flush(cards, rules.low_straight)
+        .or_else(|| has_four_of(cards))
+        .or_else(flush_or_full_house)
+        .or_else(straight_or_three_of)
+        .or_else(|| has_pairs(cards))
+        .unwrap_or_else(|| highest_card(cards));
+}
+
+/// Bit for each rank
+#[inline]
+fn rank_mask(ranks: impl Iterator<Item = Rank>) -> u16 {
+    return ranks.fold(0, |mask, rank| mask | 1 << rank as u16);
+}
+
+/// Highest card of the best straight in ranks given as [`rank_mask`]
+fn straight_high(ranks: u16, low_straight: LowStraight) -> Option<Rank> {
+    const STRAIGHT: u16 = 0b11111;
+
+    let straight = Rank::ALL.into_iter()
+        .rev()
+        .take_while(|high| *high as usize + 1 >= CARDS_IN_COMBO)
+        .find(|high| {
+            let lowest = *high as usize + 1 - CARDS_IN_COMBO;
+            return ranks >> lowest & STRAIGHT == STRAIGHT;
+        });
+
+    if straight.is_some() {
+        return straight;
+    }
+
+    let (low_straight, high) = match low_straight {
+        LowStraight::Wheel      => ([Rank::Ace, Rank::Two, Rank::Three, Rank::Four, Rank::Five], Rank::Five),
+        LowStraight::ShortDeck  => ([Rank::Ace, Rank::Six, Rank::Seven, Rank::Eight, Rank::Nine], Rank::Nine),
+        LowStraight::AceHigh    => return None,
+    };
+    let low_straight = rank_mask(low_straight.into_iter());
+
+    if ranks & low_straight == low_straight {
+        return Some(high);
     }
 
     return None;
 }
 
-fn has_four_of(hand: &Hand) -> Option<HandRanking> {
-    return hand.cards()
+fn has_straight_flush(cards: &[Card], low_straight: LowStraight) -> Option<HandRanking> {
+    return Suit::ALL.into_iter()
+        .filter_map(|suit| {
+            let suited = cards.iter().filter(|card| card.suit() == suit).map(|card| card.rank());
+            return straight_high(rank_mask(suited), low_straight);
+        })
+        .max()
+        .map(HandRanking::StraightFlush);
+}
+
+fn has_four_of(cards: &[Card]) -> Option<HandRanking> {
+    return cards
         .windows(4)
         .enumerate()
         .find(|(_, four)| {
@@ -79,19 +208,19 @@ fn has_four_of(hand: &Hand) -> Option<HandRanking> {
         .map(|(i, four)| HandRanking::FourOf(
             FourOf::new(
                 four[0].rank(), 
-                hand.cards()[if i == 0 { 4 } else { 0 }].rank()
+                cards[if i == 0 { 4 } else { 0 }].rank()
             )
         ));
 }
 
-fn has_full_house(hand: &Hand) -> Option<HandRanking> {
-    let maybe_three_of = hand.cards()
+fn has_full_house(cards: &[Card]) -> Option<HandRanking> {
+    let maybe_three_of = cards
         .windows(3)
         .find(|trey| trey[0].rank() == trey[1].rank() && trey[0].rank() == trey[2].rank());
 
     return maybe_three_of.and_then(|three_of| {
         let three_of_rank = three_of[0].rank();
-        let maybe_pair = hand.cards()
+        let maybe_pair = cards
             .windows(2)
             .find(|pair| pair[0].rank() == pair[1].rank() && pair[0].rank() != three_of_rank);
 
@@ -104,15 +233,15 @@ fn has_full_house(hand: &Hand) -> Option<HandRanking> {
     });
 }
 
-fn has_flush(hand: &Hand) -> Option<HandRanking> {
-    return has_flush_of(Suit::Diamonds, hand)
-        .or_else(|| has_flush_of(Suit::Clubs, hand))
-        .or_else(|| has_flush_of(Suit::Hearts, hand)) 
-        .or_else(|| has_flush_of(Suit::Spades, hand));
+fn has_flush(cards: &[Card]) -> Option<HandRanking> {
+    return has_flush_of(Suit::Diamonds, cards)
+        .or_else(|| has_flush_of(Suit::Clubs, cards))
+        .or_else(|| has_flush_of(Suit::Hearts, cards)) 
+        .or_else(|| has_flush_of(Suit::Spades, cards));
 }
 
-fn has_flush_of(suit: Suit, hand: &Hand) -> Option<HandRanking> {
-    let maybe_flush: Vec<_> = hand.cards().iter()
+fn has_flush_of(suit: Suit, cards: &[Card]) -> Option<HandRanking> {
+    let maybe_flush: Vec<_> = cards.iter()
         .filter(|card| card.suit() == suit)
         .map(|card| card.rank())
         .take(CARDS_IN_COMBO)
@@ -127,41 +256,17 @@ fn has_flush_of(suit: Suit, hand: &Hand) -> Option<HandRanking> {
     return None;
 }
 
-fn has_straight(hand: &Hand) -> Option<HandRanking> {
-    let mut cards = hand.cards().map(|c| c.rank()).to_vec();
-    cards.dedup();
-
-    if cards.len() < CARDS_IN_COMBO {
-        return None;
-    }
-
-    return cards
-        .windows(CARDS_IN_COMBO)
-        .find(|five| five.windows(2).all(|pair| pair[0].lower().is_some_and(|lower| lower == pair[1])))
-        .map(|straight| HandRanking::Straight(straight[0]))
-        .or_else(|| {
-            let c1 = cards[0];
-            let c2 = cards[cards.len() - 1];
-            let c3 = cards[cards.len() - 2];
-            let c4 = cards[cards.len() - 3];
-            let c5 = cards[cards.len() - 4];
-            
-            // Check for Ace low straight
-            if c2 == Rank::Two && c1 == Rank::Ace && c5 == Rank::Five && c4 == Rank::Four && c3 == Rank::Three {
-                return Some(HandRanking::Straight(Rank::Five));
-            }
-
-            return None;
-        });
+fn has_straight(cards: &[Card], low_straight: LowStraight) -> Option<HandRanking> {
+    return straight_high(rank_mask(cards.iter().map(|card| card.rank())), low_straight).map(HandRanking::Straight);
 }
 
-fn has_three_of(hand: &Hand) -> Option<HandRanking> {
-    return hand.cards()
+fn has_three_of(cards: &[Card]) -> Option<HandRanking> {
+    return cards
         .windows(3)
         .find(|trey| trey[0].rank() == trey[1].rank() && trey[0].rank() == trey[2].rank())
         .map(|trey| {
             let three_of_rank = trey[0].rank();
-            let kickers = hand.cards().iter()
+            let kickers = cards.iter()
                 .filter(|card| card.rank() != three_of_rank)
                 .take(2)
                 .map(|card| card.rank())
@@ -172,15 +277,15 @@ fn has_three_of(hand: &Hand) -> Option<HandRanking> {
         });
 }
 
-fn has_pairs(hand: &Hand) -> Option<HandRanking> {
-    let pairs = find_all_pairs(hand);
+fn has_pairs(cards: &[Card]) -> Option<HandRanking> {
+    let pairs = find_all_pairs(cards);
 
     if pairs.is_empty() {
         return None;
     }
 
     if pairs.len() == 1 {
-        let kickers = hand.cards().iter()
+        let kickers = cards.iter()
             .filter(|card| card.rank() != pairs[0])
             .take(3)
             .map(|card| card.rank())
@@ -191,7 +296,7 @@ fn has_pairs(hand: &Hand) -> Option<HandRanking> {
         ));
     }
 
-    let kicker = hand.cards().iter()
+    let kicker = cards.iter()
         .find(|card| card.rank() != pairs[0] && card.rank() != pairs[1])
         .map(|card| card.rank())
         .unwrap();
@@ -202,17 +307,17 @@ fn has_pairs(hand: &Hand) -> Option<HandRanking> {
 }
 
 /// Return all pairs in descending order by rank
-fn find_all_pairs(hand: &Hand) -> Vec<Rank> {
-    return hand.cards()
+fn find_all_pairs(cards: &[Card]) -> Vec<Rank> {
+    return cards
         .windows(2)
         .filter(|pair| pair[0].rank() == pair[1].rank())
         .map(|pair| pair[0].rank())
         .collect();
 }
 
-fn highest_card(hand: &Hand) -> HandRanking {
+fn highest_card(cards: &[Card]) -> HandRanking {
     return HandRanking::HighCard(
-        hand.cards().map(|card| card.rank())[0..5].try_into().unwrap()
+        [0, 1, 2, 3, 4].map(|i| cards[i].rank())
     );
 }
 
@@ -220,11 +325,14 @@ fn highest_card(hand: &Hand) -> HandRanking {
 mod tests {
     use crate::naive_evaluator::{
         card::{Card, Rank, Suit}, 
+        card_set::CardSet,
+        deck::Deck,
         hand::Hand,
-        hand_ranking::{HandRanking, FourOf, Flush, ThreeOf, TwoPair, Pair, FullHouse}
+        hand_ranking::{HandRanking, HandCategory, FourOf, Flush, ThreeOf, TwoPair, Pair, FullHouse},
+        notation::parse_cards
     };
 
-    use super::evaluate_five_cards;
+    use super::{evaluate_five_cards, evaluate, evaluate_card_set, evaluate_partial, five_card_combos};
 
     #[test]
     fn test_flush_royal() {
@@ -476,4 +584,98 @@ mod tests {
         let hand = Hand::try_from("AsQs5h7s9s6s5d").unwrap();
         assert_eq!(evaluate_five_cards(&hand), HandRanking::Flush(Flush::new([Rank::Ace, Rank::Queen, Rank::Nine, Rank::Seven, Rank::Six])));
     }
+
+    #[test]
+    fn test_five_and_six_cards() {
+        let flop = [Card::new(Rank::Ace, Suit::Hearts), Card::new(Rank::Seven, Suit::Diamonds), Card::new(Rank::Two, Suit::Clubs)];
+        let pocket = [Card::new(Rank::Ace, Suit::Spades), Card::new(Rank::King, Suit::Spades)];
+        let cards = [pocket[0], pocket[1], flop[0], flop[1], flop[2]];
+        assert_eq!(evaluate(&cards), HandRanking::Pair(Pair::new(Rank::Ace, [Rank::King, Rank::Seven, Rank::Two])));
+        assert_eq!(evaluate_card_set(CardSet::from(&cards[..])), evaluate(&cards));
+
+        let turn = Card::new(Rank::Seven, Suit::Spades);
+        let cards = [pocket[0], pocket[1], flop[0], flop[1], flop[2], turn];
+        assert_eq!(evaluate(&cards), HandRanking::TwoPair(TwoPair::new([Rank::Ace, Rank::Seven], Rank::King)));
+        assert_eq!(evaluate_card_set(CardSet::from(&cards[..])), evaluate(&cards));
+
+        let cards = [Card::new(Rank::Five, Suit::Clubs), Card::new(Rank::Ace, Suit::Clubs), Card::new(Rank::Three, Suit::Clubs), Card::new(Rank::Two, Suit::Clubs), Card::new(Rank::Four, Suit::Clubs)];
+        assert_eq!(evaluate(&cards), HandRanking::StraightFlush(Rank::Five));
+    }
+
+    #[test]
+    fn test_best_of_subsets() {
+        for _ in 0..2000 {
+            let mut deck = Deck::new().shuffled();
+            let cards = [(); 7].map(|_| deck.draw_card().unwrap());
+
+            for count in 5..=7 {
+                let cards = &cards[0..count];
+                let best = (0u8..1 << count)
+                    .filter(|mask| mask.count_ones() == 5)
+                    .map(|mask| {
+                        let five: Vec<_> = (0..count).filter(|i| mask & (1 << i) != 0).map(|i| cards[i]).collect();
+                        return evaluate(&five);
+                    })
+                    .max();
+
+                assert_eq!(Some(evaluate(cards)), best, "{:?}", cards);
+            }
+        }
+    }
+
+    #[test]
+    fn test_five_card_combos() {
+        assert_eq!(five_card_combos(&[1, 2, 3, 4, 5]).collect::<Vec<_>>(), vec![[1, 2, 3, 4, 5]]);
+        assert_eq!(five_card_combos(&[1, 2, 3, 4, 5, 6]).count(), 6);
+
+        let combos: Vec<_> = five_card_combos(&[1, 2, 3, 4, 5, 6, 7]).collect();
+        assert_eq!(combos.len(), 21);
+        assert!(combos.iter().all(|combo| combo.windows(2).all(|pair| pair[0] < pair[1])));
+        assert!(combos.contains(&[3, 4, 5, 6, 7]));
+    }
+
+    #[test]
+    fn test_all_five_card_hands() {
+        let mut counts = [0; 9];
+
+        for c1 in 0..52 {
+            for c2 in c1 + 1..52 {
+                for c3 in c2 + 1..52 {
+                    for c4 in c3 + 1..52 {
+                        for c5 in c4 + 1..52 {
+                            let cards = [c1, c2, c3, c4, c5].map(Card::from_index);
+                            counts[evaluate(&cards).category() as usize] += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        assert_eq!(counts, [1_302_540, 1_098_240, 123_552, 54_912, 10_200, 5_108, 3_744, 624, 40]);
+    }
+
+    #[test]
+    fn test_partial_hands() {
+        let partial = |value: &str| {
+            return evaluate_partial(&parse_cards(value).unwrap());
+        };
+
+        let pair = partial("7s2dKc7h");
+        assert_eq!(pair.category(), HandCategory::Pair);
+        assert_eq!(pair.ranks().collect::<Vec<_>>(), vec![Rank::Seven, Rank::Seven, Rank::King, Rank::Two]);
+        assert_eq!(pair.to_string(), "Pair 7. Kickers K2");
+
+        assert_eq!(partial("As").category(), HandCategory::HighCard);
+        assert_eq!(partial("3s3d2c2h").category(), HandCategory::TwoPair);
+        assert_eq!(partial("3s3d3cAh").category(), HandCategory::ThreeOf);
+        assert_eq!(partial("3s3d3c3h").category(), HandCategory::FourOf);
+
+        // Straights and flushes don't count
+        assert_eq!(partial("5h4h3h2h").category(), HandCategory::HighCard);
+
+        assert!(partial("2s2d") > partial("AsKs"));
+        assert!(partial("AsKs") > partial("AsQs"));
+        assert!(partial("KsKd3c") > partial("KhKc2c"));
+        assert!(partial("2s2d2c") > partial("AsAdKc"));
+    }
 }
@@ -0,0 +1,20 @@
+use super::{card::Card, deck::Deck, notation::parse_cards, pocket::Pocket};
+
+/// Single card like `Ah`, panics on invalid input
+pub(crate) fn card(value: &str) -> Card {
+    return Card::try_from(value).unwrap();
+}
+
+/// Pocket like `AhKd`, panics on invalid input
+pub(crate) fn pocket(value: &str) -> Pocket {
+    let cards = parse_cards(value).unwrap();
+    assert_eq!(cards.len(), 2, "expected two cards in '{}'", value);
+    return Pocket::new(cards[0], cards[1]);
+}
+
+/// Deck which deals the cards in the given order
+pub(crate) fn deck(value: &str) -> Deck {
+    let mut cards = parse_cards(value).unwrap();
+    cards.reverse();
+    return Deck::from_cards(cards);
+}
@@ -1,10 +1,14 @@
 use std::fmt::Display;
 
-use super::card::Card;
+use super::{
+    card::{Card, ParseError, ParseErrorKind},
+    card_set::CardSet,
+    notation::parse_positioned_cards
+};
 
 pub const HAND_SIZE: usize = 7;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Hand {
     // Cards in descending rank order
     cards: [Card; HAND_SIZE]
@@ -31,21 +35,29 @@ impl From<&Hand> for String {
 }
 
 impl TryFrom<&str> for Hand {
-    type Error = ();
+    type Error = ParseError;
 
+    /// Seven distinct cards in any notation supported by [`parse_cards`](super::notation::parse_cards),
+    /// e.g. `AsKd7c7h5s3d2c` or `[A♠ K♦ 7♣ 7♥ 5♠ 3♦ 2♣]`
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.len() != 14 {
-            return Err(());
+        let parsed = parse_positioned_cards(value)?;
+        let chars: Vec<_> = value.chars().collect();
+
+        if parsed.len() != HAND_SIZE {
+            let position = parsed.get(HAND_SIZE).map_or(chars.len(), |(position, _)| *position);
+            return Err(ParseError::new(ParseErrorKind::WrongLength, position, chars.get(position).copied()));
+        }
+
+        let mut used = CardSet::EMPTY;
+
+        for (position, card) in &parsed {
+            if !used.insert(*card) {
+                return Err(ParseError::new(ParseErrorKind::DuplicateCard, *position, Some(chars[*position])));
+            }
         }
-        
-        let c1 = Card::try_from(&value[0..2])?;
-        let c2 = Card::try_from(&value[2..4])?;
-        let c3 = Card::try_from(&value[4..6])?;
-        let c4 = Card::try_from(&value[6..8])?;
-        let c5 = Card::try_from(&value[8..10])?;
-        let c6 = Card::try_from(&value[10..12])?;
-        let c7 = Card::try_from(&value[12..14])?;
-        return Ok(Hand::new([c1, c2, c3, c4, c5, c6, c7]));
+
+        let mut cards = parsed.into_iter().map(|(_, card)| card);
+        return Ok(Hand::new([(); HAND_SIZE].map(|_| cards.next().unwrap())));
     }
 }
 
@@ -54,3 +66,29 @@ impl Display for Hand {
         return write!(f, "{}", String::from(self));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{card::{Card, ParseError, ParseErrorKind}, hand::Hand};
+
+    #[test]
+    fn test_parse_hand() {
+        let hand = Hand::try_from("AsKd7c7h5s3d2c").unwrap();
+        assert_eq!(hand.cards()[0], Card::try_from("As").unwrap());
+        assert_eq!(hand.to_string(), "AsKd7h7c5s3d2c");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Hand::try_from("AsKx7c7h5s3d2c"), Err(ParseError::new(ParseErrorKind::UnknownSuit, 3, Some('x'))));
+        assert_eq!(Hand::try_from("AsKd7c7h5s3d1c"), Err(ParseError::new(ParseErrorKind::UnknownRank, 12, Some('1'))));
+        assert_eq!(Hand::try_from("AsKd7c7h5s3d"), Err(ParseError::new(ParseErrorKind::WrongLength, 12, None)));
+        assert_eq!(Hand::try_from("AsKd7c7h5s3d2c2d"), Err(ParseError::new(ParseErrorKind::WrongLength, 14, Some('2'))));
+        assert_eq!(Hand::try_from("AsKd7c7h5s3dKd"), Err(ParseError::new(ParseErrorKind::DuplicateCard, 12, Some('K'))));
+
+        // Multi-byte characters don't break slicing
+        assert_eq!(Hand::try_from("A♠Kd7c7h5s3d2★"), Err(ParseError::new(ParseErrorKind::UnknownSuit, 13, Some('★'))));
+        assert_eq!(Hand::try_from("[A♠ K♦ 7♣ 7♥ 5♠ 3♦ 2♣]"), Hand::try_from("AsKd7c7h5s3d2c"));
+        assert_eq!(Hand::try_from("as kd 7c 7h 5s 3d 10c"), Hand::try_from("AsKd7c7h5s3dTc"));
+    }
+}
@@ -4,8 +4,8 @@ use super::card::Rank;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct FourOf {
-    rank: Rank,
-    kicker: Rank
+    pub(super) rank: Rank,
+    pub(super) kicker: Rank
 }
 
 impl FourOf {
@@ -17,8 +17,8 @@ impl FourOf {
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct FullHouse {
-    three_of_rank: Rank,
-    pair_of_rank: Rank
+    pub(super) three_of_rank: Rank,
+    pub(super) pair_of_rank: Rank
 }
 
 impl FullHouse {
@@ -30,7 +30,7 @@ impl FullHouse {
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct Flush {
-    ranks: [Rank; 5]
+    pub(super) ranks: [Rank; 5]
 }
 
 impl Flush {
@@ -44,8 +44,8 @@ impl Flush {
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct ThreeOf {
-    three_of: Rank,
-    kickers: [Rank; 2]
+    pub(super) three_of: Rank,
+    pub(super) kickers: [Rank; 2]
 }
 
 impl ThreeOf {
@@ -64,8 +64,8 @@ impl ThreeOf {
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct TwoPair {
-    pairs: [Rank; 2],
-    kicker: Rank
+    pub(super) pairs: [Rank; 2],
+    pub(super) kicker: Rank
 }
 
 impl TwoPair {
@@ -79,8 +79,8 @@ impl TwoPair {
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct Pair {
-    pair_rank: Rank,
-    kickers: [Rank; 3]
+    pub(super) pair_rank: Rank,
+    pub(super) kickers: [Rank; 3]
 }
 
 impl Pair {
@@ -102,7 +102,97 @@ pub enum HandRanking {
     Flush(Flush),
     FullHouse(FullHouse),
     FourOf(FourOf),
-    StraightFlush(Rank)
+    StraightFlush(Rank),
+    /// Only possible with wild cards
+    FiveOf(Rank)
+}
+
+/// Hand ranking without tie breaking ranks
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum HandCategory {
+    HighCard,
+    Pair,
+    TwoPair,
+    ThreeOf,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOf,
+    StraightFlush,
+    FiveOf
+}
+
+impl HandRanking {
+    #[inline]
+    pub fn category(&self) -> HandCategory {
+        return match self {
+            HandRanking::HighCard(_)        => HandCategory::HighCard,
+            HandRanking::Pair(_)            => HandCategory::Pair,
+            HandRanking::TwoPair(_)         => HandCategory::TwoPair,
+            HandRanking::ThreeOf(_)         => HandCategory::ThreeOf,
+            HandRanking::Straight(_)        => HandCategory::Straight,
+            HandRanking::Flush(_)           => HandCategory::Flush,
+            HandRanking::FullHouse(_)       => HandCategory::FullHouse,
+            HandRanking::FourOf(_)          => HandCategory::FourOf,
+            HandRanking::StraightFlush(_)   => HandCategory::StraightFlush,
+            HandRanking::FiveOf(_)          => HandCategory::FiveOf,
+        };
+    }
+}
+
+/// Ranking of 1 to 4 cards, e.g. stud upcards. Straights and flushes don't count.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct PartialHandRanking {
+    category: HandCategory,
+    /// Ranks of the cards, paired ranks first, then side cards in descending order
+    ranks: [Option<Rank>; 4]
+}
+
+impl PartialHandRanking {
+    /// `category` - high card, pair, two pair, three of or four of
+    /// 
+    /// `ranks` - 1 to 4 ranks, paired ranks first, then side cards in descending order
+    pub fn new(category: HandCategory, ranks: &[Rank]) -> Self {
+        debug_assert!((1..=4).contains(&ranks.len()));
+        debug_assert!(matches!(
+            category, 
+            HandCategory::HighCard | HandCategory::Pair | HandCategory::TwoPair | HandCategory::ThreeOf | HandCategory::FourOf
+        ));
+
+        let mut padded = [None; 4];
+
+        for (padded, rank) in padded.iter_mut().zip(ranks) {
+            *padded = Some(*rank);
+        }
+
+        return Self { category, ranks: padded };
+    }
+
+    #[inline]
+    pub const fn category(&self) -> HandCategory {
+        return self.category;
+    }
+
+    /// Paired ranks first, then side cards in descending order
+    #[inline]
+    pub fn ranks(&self) -> impl Iterator<Item = Rank> + '_ {
+        return self.ranks.iter().map_while(|rank| *rank);
+    }
+}
+
+impl Display for PartialHandRanking {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ranks: Vec<_> = self.ranks().collect();
+        let kickers = |used: usize| ranks[used..].iter().map(|r| r.to_string()).collect::<String>();
+
+        return match self.category {
+            HandCategory::Pair                          => write!(f, "Pair {}. Kickers {}", ranks[0], kickers(2)),
+            HandCategory::TwoPair                       => write!(f, "Two pair {}{}", ranks[0], ranks[2]),
+            HandCategory::ThreeOf                       => write!(f, "Three of {}. Kickers {}", ranks[0], kickers(3)),
+            HandCategory::FourOf                        => write!(f, "Four {}", ranks[0]),
+            _                                           => write!(f, "High card {}", kickers(0)),
+        };
+    }
 }
 
 impl From<&HandRanking> for String {
@@ -118,6 +208,7 @@ impl From<&HandRanking> for String {
             HandRanking::FullHouse(full_house)  => format!("Full house {}{}", full_house.three_of_rank, full_house.pair_of_rank),
             HandRanking::FourOf(four)           => format!("Four {}. Kicker {}", four.rank, four.kicker),
             HandRanking::StraightFlush(high)    => if *high == Rank::Ace { String::from("Royal flush") } else { format!("Straight flush {}", high) },
+            HandRanking::FiveOf(rank)           => format!("Five {}", rank),
         };
     }
 }
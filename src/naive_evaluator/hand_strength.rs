@@ -0,0 +1,292 @@
+use std::{fmt::Display, sync::OnceLock};
+
+use crate::lookup_table_evaluator::indexing::{combinations_count, index_of_combination};
+
+use super::{
+    card::Rank,
+    hand_ranking::{HandRanking, FourOf, FullHouse, Flush, ThreeOf, TwoPair, Pair}
+};
+
+/// Number of distinct 5-card hand equivalence classes
+pub const HAND_STRENGTH_COUNT: usize = 7462;
+
+const RANKS: usize = Rank::ALL.len();
+/// Five-high to ace-high
+const STRAIGHTS: usize = 10;
+/// Sets of five distinct ranks which are not straights
+const UNPAIRED: usize = combinations_count(RANKS, 5) - STRAIGHTS;
+
+// First strength of each category
+const PAIR: usize = UNPAIRED;
+const TWO_PAIR: usize = PAIR + RANKS * combinations_count(RANKS - 1, 3);
+const THREE_OF: usize = TWO_PAIR + combinations_count(RANKS, 2) * (RANKS - 2);
+const STRAIGHT: usize = THREE_OF + RANKS * combinations_count(RANKS - 1, 2);
+const FLUSH: usize = STRAIGHT + STRAIGHTS;
+const FULL_HOUSE: usize = FLUSH + UNPAIRED;
+const FOUR_OF: usize = FULL_HOUSE + RANKS * (RANKS - 1);
+const STRAIGHT_FLUSH: usize = FOUR_OF + RANKS * (RANKS - 1);
+
+const _: () = assert!(STRAIGHT_FLUSH + STRAIGHTS == HAND_STRENGTH_COUNT);
+
+/// Dense number of a hand equivalence class in `0..7462`.
+/// Stronger hands have greater values, so comparing hands is a single integer comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandStrength(u16);
+
+impl HandStrength {
+    /// Worst possible hand: 7-5-4-3-2 high
+    pub const MIN: HandStrength = HandStrength(0);
+    /// Royal flush
+    pub const MAX: HandStrength = HandStrength(HAND_STRENGTH_COUNT as u16 - 1);
+
+    #[inline]
+    pub fn new(value: u16) -> Option<Self> {
+        if (value as usize) < HAND_STRENGTH_COUNT {
+            return Some(Self(value));
+        }
+
+        return None;
+    }
+
+    #[inline]
+    pub const fn value(&self) -> u16 {
+        return self.0;
+    }
+}
+
+impl From<HandRanking> for HandStrength {
+    /// Panics on five of a kind, which can only be made with wild cards
+    fn from(value: HandRanking) -> Self {
+        let index = match value {
+            HandRanking::HighCard(ranks)    => unpaired_index(&ranks),
+            HandRanking::Pair(Pair { pair_rank, kickers }) => {
+                PAIR + pair_rank as usize * combinations_count(RANKS - 1, 3) + rank_set_index(&kickers, &[pair_rank])
+            },
+            HandRanking::TwoPair(TwoPair { pairs, kicker }) => {
+                TWO_PAIR + rank_set_index(&pairs, &[]) * (RANKS - 2) + rank_set_index(&[kicker], &pairs)
+            },
+            HandRanking::ThreeOf(ThreeOf { three_of, kickers }) => {
+                THREE_OF + three_of as usize * combinations_count(RANKS - 1, 2) + rank_set_index(&kickers, &[three_of])
+            },
+            HandRanking::Straight(high)     => STRAIGHT + straight_index(high),
+            HandRanking::Flush(flush)       => FLUSH + unpaired_index(&flush.ranks),
+            HandRanking::FullHouse(FullHouse { three_of_rank, pair_of_rank }) => {
+                FULL_HOUSE + three_of_rank as usize * (RANKS - 1) + rank_set_index(&[pair_of_rank], &[three_of_rank])
+            },
+            HandRanking::FourOf(FourOf { rank, kicker }) => {
+                FOUR_OF + rank as usize * (RANKS - 1) + rank_set_index(&[kicker], &[rank])
+            },
+            HandRanking::StraightFlush(high) => STRAIGHT_FLUSH + straight_index(high),
+            HandRanking::FiveOf(_)          => panic!("five of a kind can only be made with wild cards"),
+        };
+
+        return Self(index as u16);
+    }
+}
+
+impl From<HandStrength> for HandRanking {
+    #[inline]
+    fn from(value: HandStrength) -> Self {
+        return rankings()[value.0 as usize];
+    }
+}
+
+impl Display for HandStrength {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}", HandRanking::from(*self));
+    }
+}
+
+/// Colexicographic index of distinct `ranks` in descending order among sets of ranks without `excluded` ones
+fn rank_set_index(ranks: &[Rank], excluded: &[Rank]) -> usize {
+    let mut positions = [0; 5];
+
+    for (position, rank) in positions.iter_mut().zip(ranks.iter().rev()) {
+        *position = *rank as usize - excluded.iter().filter(|excluded| *excluded < rank).count();
+    }
+
+    return index_of_combination(&positions[..ranks.len()]);
+}
+
+/// Index of five distinct ranks in descending order among sets which are not straights
+fn unpaired_index(ranks: &[Rank; 5]) -> usize {
+    debug_assert!(!is_straight(ranks));
+
+    let index = rank_set_index(ranks, &[]);
+    let straights_below = Rank::ALL[Rank::Five as usize..]
+        .iter()
+        .filter(|high| rank_set_index(&straight_ranks(**high), &[]) < index)
+        .count();
+
+    return index - straights_below;
+}
+
+/// Ranks of the straight in descending order, the wheel starts with the ace
+fn straight_ranks(high: Rank) -> [Rank; 5] {
+    if high == Rank::Five {
+        return [Rank::Ace, Rank::Five, Rank::Four, Rank::Three, Rank::Two];
+    }
+
+    return std::array::from_fn(|i| Rank::ALL[high as usize - i]);
+}
+
+#[inline]
+fn straight_index(high: Rank) -> usize {
+    return high as usize - Rank::Five as usize;
+}
+
+/// All distinct hand rankings in ascending order
+fn rankings() -> &'static [HandRanking] {
+    static RANKINGS: OnceLock<Vec<HandRanking>> = OnceLock::new();
+    return RANKINGS.get_or_init(generate_rankings);
+}
+
+fn generate_rankings() -> Vec<HandRanking> {
+    let mut rankings = Vec::with_capacity(HAND_STRENGTH_COUNT);
+
+    for ranks in rank_subsets::<5>(&[]) {
+        if is_straight(&ranks) {
+            continue;
+        }
+
+        rankings.push(HandRanking::HighCard(ranks));
+        rankings.push(HandRanking::Flush(Flush::new(ranks)));
+    }
+
+    for pair in Rank::ALL {
+        for kickers in rank_subsets::<3>(&[pair]) {
+            rankings.push(HandRanking::Pair(Pair::new(pair, kickers)));
+        }
+    }
+
+    for pairs in rank_subsets::<2>(&[]) {
+        for [kicker] in rank_subsets::<1>(&pairs) {
+            rankings.push(HandRanking::TwoPair(TwoPair::new(pairs, kicker)));
+        }
+    }
+
+    for three_of in Rank::ALL {
+        for kickers in rank_subsets::<2>(&[three_of]) {
+            rankings.push(HandRanking::ThreeOf(ThreeOf::new(three_of, kickers)));
+        }
+    }
+
+    for high in Rank::ALL.into_iter().filter(|rank| *rank >= Rank::Five) {
+        rankings.push(HandRanking::Straight(high));
+        rankings.push(HandRanking::StraightFlush(high));
+    }
+
+    for first in Rank::ALL {
+        for second in Rank::ALL.into_iter().filter(|rank| *rank != first) {
+            rankings.push(HandRanking::FullHouse(FullHouse::new(second, first)));
+            rankings.push(HandRanking::FourOf(FourOf::new(first, second)));
+        }
+    }
+
+    rankings.sort_unstable();
+    debug_assert_eq!(rankings.len(), HAND_STRENGTH_COUNT);
+    return rankings;
+}
+
+/// All sets of `N` distinct ranks not in `excluded`, each in descending order
+fn rank_subsets<const N: usize>(excluded: &[Rank]) -> impl Iterator<Item = [Rank; N]> + '_ {
+    return (0u16..1 << Rank::ALL.len())
+        .filter(|mask| mask.count_ones() as usize == N)
+        .filter(move |mask| excluded.iter().all(|rank| mask & (1 << *rank as u16) == 0))
+        .map(|mask| {
+            let mut ranks = Rank::ALL.into_iter().rev().filter(|rank| mask & (1 << *rank as u16) != 0);
+            return [(); N].map(|_| ranks.next().unwrap());
+        });
+}
+
+/// `ranks` - distinct ranks in descending order
+fn is_straight(ranks: &[Rank; 5]) -> bool {
+    return ranks == &[Rank::Ace, Rank::Five, Rank::Four, Rank::Three, Rank::Two] ||
+        ranks.windows(2).all(|pair| pair[0].lower() == Some(pair[1]));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::naive_evaluator::{
+        card::{Card, Rank, Suit},
+        hand::Hand,
+        hand_ranking::{HandRanking, Pair},
+        evaluator::{evaluate, evaluate_five_cards}
+    };
+
+    use super::{HandStrength, HAND_STRENGTH_COUNT, rankings};
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(rankings().len(), HAND_STRENGTH_COUNT);
+
+        for value in 0..HAND_STRENGTH_COUNT as u16 {
+            let strength = HandStrength::new(value).unwrap();
+            assert_eq!(HandStrength::from(HandRanking::from(strength)), strength);
+        }
+
+        assert_eq!(HandStrength::new(HAND_STRENGTH_COUNT as u16), None);
+    }
+
+    #[test]
+    fn test_every_evaluated_hand_has_strength() {
+        let mut strengths = HashSet::new();
+
+        // One hand per rank multiset, plus a flush when all ranks differ, covers every equivalence class
+        for a in 0..13 {
+            for b in a..13 {
+                for c in b..13 {
+                    for d in c..13 {
+                        for e in d..13 {
+                            if a == e {
+                                continue;
+                            }
+
+                            let ranks = [a, b, c, d, e].map(|rank| Rank::ALL[rank]);
+                            let cards: [Card; 5] = std::array::from_fn(|i| {
+                                let copies = ranks[..i].iter().filter(|rank| **rank == ranks[i]).count();
+                                return Card::new(ranks[i], Suit::ALL[copies]);
+                            });
+
+                            let mut hands = vec![cards];
+                            if cards.iter().all(|card| card.suit() == Suit::Clubs) {
+                                let mut offsuit = cards;
+                                offsuit[4] = Card::new(ranks[4], Suit::Diamonds);
+                                hands.push(offsuit);
+                            }
+
+                            for hand in hands {
+                                strengths.insert(HandStrength::from(evaluate(&hand)));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        assert_eq!(strengths.len(), HAND_STRENGTH_COUNT);
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(
+            HandRanking::from(HandStrength::MIN), 
+            HandRanking::HighCard([Rank::Seven, Rank::Five, Rank::Four, Rank::Three, Rank::Two])
+        );
+        assert_eq!(HandRanking::from(HandStrength::MAX), HandRanking::StraightFlush(Rank::Ace));
+    }
+
+    #[test]
+    fn test_order_matches_ranking() {
+        let pair = HandStrength::from(HandRanking::Pair(Pair::new(Rank::Ace, [Rank::King, Rank::Queen, Rank::Two])));
+        let straight = HandStrength::from(HandRanking::Straight(Rank::Five));
+        assert!(pair < straight);
+
+        let hand1 = Hand::try_from("AsAcQd5s4s3s2s").unwrap();
+        let hand2 = Hand::try_from("AdKcJcTcQc9c2c").unwrap();
+        assert!(HandStrength::from(evaluate_five_cards(&hand1)) < HandStrength::from(evaluate_five_cards(&hand2)));
+    }
+}
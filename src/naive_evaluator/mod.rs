@@ -1,6 +1,15 @@
 pub mod card;
+pub mod card_set;
 pub mod pocket;
 pub mod hand;
 pub mod hand_ranking;
+pub mod hand_strength;
 pub mod evaluator;
+pub mod best_hand;
 pub mod deck;
+pub mod dealer;
+pub mod notation;
+pub mod board;
+
+#[cfg(test)]
+pub(crate) mod fixtures;
@@ -0,0 +1,160 @@
+use super::card::{Card, ParseError, ParseErrorKind, Rank, Suit};
+
+/// Output style of cards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CardFormat {
+    /// Suits as `♠♥♦♣` instead of `shdc`
+    pub suit_symbols: bool,
+    /// Ten as `10` instead of `T`
+    pub ten_as_digits: bool,
+    /// Put between cards of a list
+    pub separator: &'static str,
+    /// Wrap lists in square brackets
+    pub brackets: bool
+}
+
+impl CardFormat {
+    /// `AsKd`, same as `Display` of cards
+    pub const COMPACT: CardFormat = CardFormat { suit_symbols: false, ten_as_digits: false, separator: "", brackets: false };
+    /// `A♠ K♦`
+    pub const SYMBOLS: CardFormat = CardFormat { suit_symbols: true, ten_as_digits: false, separator: " ", brackets: false };
+    /// `[As Kd]`
+    pub const BRACKETS: CardFormat = CardFormat { suit_symbols: false, ten_as_digits: false, separator: " ", brackets: true };
+
+    pub fn card(&self, card: Card) -> String {
+        let rank = match card.rank() {
+            Rank::Ten if self.ten_as_digits => String::from("10"),
+            rank                            => rank.to_string(),
+        };
+        let suit = if self.suit_symbols { card.suit().symbol() } else { char::from(card.suit()) };
+
+        return format!("{}{}", rank, suit);
+    }
+
+    pub fn cards(&self, cards: &[Card]) -> String {
+        let list = cards.iter().map(|card| self.card(*card)).collect::<Vec<_>>().join(self.separator);
+
+        if self.brackets {
+            return format!("[{}]", list);
+        }
+
+        return list;
+    }
+}
+
+impl Default for CardFormat {
+    #[inline]
+    fn default() -> Self {
+        return Self::COMPACT;
+    }
+}
+
+/// Parses a list of cards in any of the supported notations: `AsKd`, `as kd`, `As, Kd`, `[A♠ K♦]` or `10s 9s`.
+/// Duplicated cards are kept.
+#[inline]
+pub fn parse_cards(value: &str) -> Result<Vec<Card>, ParseError> {
+    return parse_positioned_cards(value).map(|cards| cards.into_iter().map(|(_, card)| card).collect());
+}
+
+/// Parsed cards together with positions where they start in the input, counted in characters
+pub(crate) fn parse_positioned_cards(value: &str) -> Result<Vec<(usize, Card)>, ParseError> {
+    let chars: Vec<_> = value.chars().collect();
+    let mut start = chars.iter().position(|c| !c.is_whitespace()).unwrap_or(chars.len());
+    let mut end = chars.iter().rposition(|c| !c.is_whitespace()).map_or(start, |last| last + 1);
+
+    if chars.get(start) == Some(&'[') {
+        if end <= start + 1 || chars[end - 1] != ']' {
+            return Err(ParseError::new(ParseErrorKind::UnbalancedBracket, start, Some('[')));
+        }
+
+        start += 1;
+        end -= 1;
+    }
+
+    let mut cards = Vec::new();
+    let mut i = start;
+
+    while i < end {
+        if is_separator(chars[i]) {
+            i += 1;
+            continue;
+        }
+
+        if chars[i] == '[' || chars[i] == ']' {
+            return Err(ParseError::new(ParseErrorKind::UnbalancedBracket, i, Some(chars[i])));
+        }
+
+        let (card, next) = parse_card(&chars[..end], i)?;
+        cards.push((i, card));
+        i = next;
+    }
+
+    return Ok(cards);
+}
+
+/// Parses a single card starting at `start`, returns it with the position right after it
+pub(crate) fn parse_card(chars: &[char], start: usize) -> Result<(Card, usize), ParseError> {
+    let rank = *chars.get(start).ok_or(ParseError::new(ParseErrorKind::WrongLength, start, None))?;
+    let (rank, suit_at) = if rank == '1' && chars.get(start + 1) == Some(&'0') {
+        (Rank::Ten, start + 2)
+    } else {
+        (Rank::try_from(rank).map_err(|error| error.offset(start))?, start + 1)
+    };
+
+    let suit = *chars.get(suit_at).ok_or(ParseError::new(ParseErrorKind::WrongLength, suit_at, None))?;
+    let suit = Suit::try_from(suit).map_err(|error| error.offset(suit_at))?;
+    return Ok((Card::new(rank, suit), suit_at + 1));
+}
+
+#[inline]
+fn is_separator(character: char) -> bool {
+    return character.is_whitespace() || character == ',';
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::card::{Card, ParseError, ParseErrorKind};
+
+    use super::{parse_cards, CardFormat};
+
+    fn cards(values: &[&str]) -> Vec<Card> {
+        return values.iter().map(|value| Card::try_from(*value).unwrap()).collect();
+    }
+
+    #[test]
+    fn test_parse_lists() {
+        let expected = cards(&["Ah", "Kd", "Ts"]);
+
+        for notation in ["AhKdTs", "ah kd ts", "Ah, Kd, Ts", "[Ah Kd Ts]", " [ A♥ K♦ 10♠ ] ", "Ah,Kd,10s", "AhKd\tTs"] {
+            assert_eq!(parse_cards(notation), Ok(expected.clone()), "{}", notation);
+        }
+
+        assert_eq!(parse_cards(""), Ok(Vec::new()));
+        assert_eq!(parse_cards("[]"), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_parse_list_errors() {
+        assert_eq!(parse_cards("A♥ Kx"), Err(ParseError::new(ParseErrorKind::UnknownSuit, 4, Some('x'))));
+        assert_eq!(parse_cards("A♥ K"), Err(ParseError::new(ParseErrorKind::WrongLength, 4, None)));
+        assert_eq!(parse_cards("[Ah Kd"), Err(ParseError::new(ParseErrorKind::UnbalancedBracket, 0, Some('['))));
+        assert_eq!(parse_cards(" ["), Err(ParseError::new(ParseErrorKind::UnbalancedBracket, 1, Some('['))));
+        assert_eq!(parse_cards("Ah Kd]"), Err(ParseError::new(ParseErrorKind::UnbalancedBracket, 5, Some(']'))));
+        assert_eq!(parse_cards("[Ah [Kd]"), Err(ParseError::new(ParseErrorKind::UnbalancedBracket, 4, Some('['))));
+        assert_eq!(parse_cards("[Ah Kd").unwrap_err().to_string(), "unbalanced bracket '[' at position 0");
+        assert_eq!(parse_cards("Ah; Kd"), Err(ParseError::new(ParseErrorKind::UnknownRank, 2, Some(';'))));
+    }
+
+    #[test]
+    fn test_format() {
+        let list = cards(&["Ah", "Td", "2c"]);
+
+        assert_eq!(CardFormat::COMPACT.cards(&list), "AhTd2c");
+        assert_eq!(CardFormat::SYMBOLS.cards(&list), "A♥ T♦ 2♣");
+        assert_eq!(CardFormat::BRACKETS.cards(&list), "[Ah Td 2c]");
+
+        let format = CardFormat { ten_as_digits: true, separator: ", ", ..CardFormat::SYMBOLS };
+        assert_eq!(format.cards(&list), "A♥, 10♦, 2♣");
+        assert_eq!(parse_cards(&format.cards(&list)), Ok(list));
+    }
+}
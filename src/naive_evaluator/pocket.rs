@@ -1,5 +1,6 @@
 use super::card::Card;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Pocket {
     cards: [Card; 2]
 }
@@ -21,4 +22,9 @@ impl Pocket {
     pub const fn card2(&self) -> Card {
         return self.cards[1];
     }
+
+    #[inline]
+    pub const fn cards(&self) -> &[Card; 2] {
+        return &self.cards;
+    }
 }
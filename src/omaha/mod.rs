@@ -0,0 +1,188 @@
+use crate::naive_evaluator::{
+    card::{Card, ParseError, ParseErrorKind},
+    card_set::CardSet,
+    hand_ranking::HandRanking,
+    evaluator::evaluate,
+    notation::parse_positioned_cards
+};
+use crate::lowball::ace_to_five::{AceToFiveLow, evaluate_eight_or_better};
+
+/// Omaha hand uses exactly this many hole cards
+pub const HOLE_CARDS_USED: usize = 2;
+/// Omaha hand uses exactly this many board cards
+pub const BOARD_CARDS_USED: usize = 3;
+
+pub const MIN_POCKET_SIZE: usize = 4;
+/// Six card Omaha
+pub const MAX_POCKET_SIZE: usize = 6;
+
+/// Hole cards of 4, 5 or 6 card Omaha
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OmahaPocket {
+    cards: Vec<Card>
+}
+
+impl OmahaPocket {
+    #[inline]
+    pub fn cards(&self) -> &[Card] {
+        return &self.cards;
+    }
+}
+
+impl TryFrom<&[Card]> for OmahaPocket {
+    type Error = ();
+
+    /// Fails on wrong number of cards or duplicated cards
+    fn try_from(value: &[Card]) -> Result<Self, Self::Error> {
+        if !(MIN_POCKET_SIZE..=MAX_POCKET_SIZE).contains(&value.len()) || CardSet::from(value).len() != value.len() {
+            return Err(());
+        }
+
+        return Ok(Self { cards: value.to_vec() });
+    }
+}
+
+impl TryFrom<&str> for OmahaPocket {
+    type Error = ParseError;
+
+    /// 4 to 6 distinct cards in any notation supported by [`parse_cards`](crate::naive_evaluator::notation::parse_cards),
+    /// e.g. `AhKh7c2d`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parsed = parse_positioned_cards(value)?;
+        let chars: Vec<_> = value.chars().collect();
+
+        if !(MIN_POCKET_SIZE..=MAX_POCKET_SIZE).contains(&parsed.len()) {
+            let position = parsed.get(MAX_POCKET_SIZE).map_or(chars.len(), |(position, _)| *position);
+            return Err(ParseError::new(ParseErrorKind::WrongLength, position, chars.get(position).copied()));
+        }
+
+        let mut cards = Vec::with_capacity(parsed.len());
+
+        for (position, card) in parsed {
+            if cards.contains(&card) {
+                return Err(ParseError::new(ParseErrorKind::DuplicateCard, position, Some(chars[position])));
+            }
+
+            cards.push(card);
+        }
+
+        return Ok(Self { cards });
+    }
+}
+
+/// Best hand made of exactly two hole cards and exactly three board cards.
+/// 
+/// `board` - 3 to 5 community cards
+#[inline]
+pub fn evaluate_omaha(pocket: &OmahaPocket, board: &[Card]) -> HandRanking {
+    return best_combination(pocket, board, evaluate);
+}
+
+/// Best eight-or-better low made of exactly two hole cards and exactly three board cards,
+/// `None` if there is no qualifying low.
+/// 
+/// `board` - 3 to 5 community cards
+#[inline]
+pub fn evaluate_omaha_eight_or_better(pocket: &OmahaPocket, board: &[Card]) -> Option<AceToFiveLow> {
+    return best_combination(pocket, board, evaluate_eight_or_better);
+}
+
+/// Greatest value of `evaluate` over all combinations of two hole cards and three board cards
+fn best_combination<T: Ord>(pocket: &OmahaPocket, board: &[Card], evaluate: impl Fn(&[Card]) -> T) -> T {
+    assert!((BOARD_CARDS_USED..=5).contains(&board.len()), "expected 3 to 5 board cards, got {}", board.len());
+
+    let hole = pocket.cards();
+    let mut best = None;
+
+    for (i1, hole1) in hole.iter().enumerate() {
+        for hole2 in &hole[i1 + 1..] {
+            for (j1, board1) in board.iter().enumerate() {
+                for (j2, board2) in board.iter().enumerate().skip(j1 + 1) {
+                    for board3 in &board[j2 + 1..] {
+                        let value = evaluate(&[*hole1, *hole2, *board1, *board2, *board3]);
+                        best = best.max(Some(value));
+                    }
+                }
+            }
+        }
+    }
+
+    return best.unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{
+        card::{Card, ParseError, ParseErrorKind, Rank},
+        hand_ranking::{HandRanking, ThreeOf, Flush, FullHouse},
+        evaluator::evaluate,
+        notation::parse_cards
+    };
+
+    use super::{OmahaPocket, evaluate_omaha, evaluate_omaha_eight_or_better};
+
+    #[test]
+    fn test_must_use_two_hole_cards() {
+        let pocket = OmahaPocket::try_from("QhJsTs3d").unwrap();
+        let board = parse_cards("Ah9h6h2hKc").unwrap();
+
+        // Four hearts on board and one in hand make no flush
+        assert_eq!(evaluate_omaha(&pocket, &board), HandRanking::HighCard([Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Nine]));
+        assert!(matches!(evaluate(&[&pocket.cards()[0..2], &board[..]].concat()), HandRanking::Flush(_)));
+    }
+
+    #[test]
+    fn test_must_use_three_board_cards() {
+        let pocket = OmahaPocket::try_from("KcQd4s3s").unwrap();
+        let board = parse_cards("7c7d7hKs2d").unwrap();
+        assert_eq!(evaluate_omaha(&pocket, &board), HandRanking::ThreeOf(ThreeOf::new(Rank::Seven, [Rank::King, Rank::Queen])));
+
+        let pocket = OmahaPocket::try_from("KcKd4s3s").unwrap();
+        assert_eq!(evaluate_omaha(&pocket, &board), HandRanking::FullHouse(FullHouse::new(Rank::Seven, Rank::King)));
+    }
+
+    #[test]
+    fn test_flop() {
+        let pocket = OmahaPocket::try_from("AhKh7c2d").unwrap();
+        let board = parse_cards("Qh9h3h").unwrap();
+        assert_eq!(evaluate_omaha(&pocket, &board), HandRanking::Flush(Flush::new([Rank::Ace, Rank::King, Rank::Queen, Rank::Nine, Rank::Three])));
+    }
+
+    #[test]
+    fn test_five_and_six_card_pockets() {
+        let pocket = OmahaPocket::try_from("2c3dAhKh7c").unwrap();
+        let board = parse_cards("Qh9h3hJs").unwrap();
+        assert!(matches!(evaluate_omaha(&pocket, &board), HandRanking::Flush(_)));
+
+        let pocket = OmahaPocket::try_from("2c3d4s5s8d8c").unwrap();
+        let board = parse_cards("8h6d7c").unwrap();
+        assert_eq!(evaluate_omaha(&pocket, &board), HandRanking::Straight(Rank::Eight));
+
+        let board = parse_cards("8h6dKc").unwrap();
+        assert_eq!(evaluate_omaha(&pocket, &board), HandRanking::ThreeOf(ThreeOf::new(Rank::Eight, [Rank::King, Rank::Six])));
+    }
+
+    #[test]
+    fn test_eight_or_better() {
+        let board = parse_cards("2h5d8cKsQs").unwrap();
+        let pocket = OmahaPocket::try_from("AhAs3c9d").unwrap();
+        assert_eq!(evaluate_omaha_eight_or_better(&pocket, &board).unwrap().to_string(), "8-5-3-2-A");
+
+        // Only one low hole card
+        let pocket = OmahaPocket::try_from("AhKhQcJd").unwrap();
+        assert_eq!(evaluate_omaha_eight_or_better(&pocket, &board), None);
+
+        // Only two low board cards
+        let pocket = OmahaPocket::try_from("Ah2s3c4d").unwrap();
+        assert_eq!(evaluate_omaha_eight_or_better(&pocket, &parse_cards("2h5dKcKsQs").unwrap()), None);
+    }
+
+    #[test]
+    fn test_invalid_pockets() {
+        assert_eq!(OmahaPocket::try_from("AhKh7c"), Err(ParseError::new(ParseErrorKind::WrongLength, 6, None)));
+        assert_eq!(OmahaPocket::try_from("AhKh7c2d3d4d5d"), Err(ParseError::new(ParseErrorKind::WrongLength, 12, Some('5'))));
+        assert_eq!(OmahaPocket::try_from("AhAh7c2d"), Err(ParseError::new(ParseErrorKind::DuplicateCard, 2, Some('A'))));
+        assert_eq!(OmahaPocket::try_from("AhKh7c2x"), Err(ParseError::new(ParseErrorKind::UnknownSuit, 7, Some('x'))));
+        assert_eq!(OmahaPocket::try_from(&[Card::try_from("Ah").unwrap(); 4][..]), Err(()));
+    }
+}
@@ -0,0 +1,290 @@
+use std::{cmp::Reverse, collections::{BTreeMap, BTreeSet}, fmt::Display};
+
+use crate::naive_evaluator::{card::{Card, Rank, Suit}, pocket::Pocket};
+
+pub mod parser;
+
+pub use parser::RangeParseError;
+
+/// Starting hand class, e.g. `TT`, `AKs` or `AKo`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandClass {
+    Pair(Rank),
+    /// High and low ranks
+    Suited(Rank, Rank),
+    /// High and low ranks
+    Offsuit(Rank, Rank)
+}
+
+impl HandClass {
+    /// All combos of the class, 6 for pairs, 4 for suited and 12 for offsuit hands
+    pub fn combos(&self) -> Vec<Pocket> {
+        let mut combos = Vec::with_capacity(12);
+
+        for suit1 in Suit::ALL {
+            for suit2 in Suit::ALL {
+                let combo = match *self {
+                    HandClass::Pair(rank) if suit1 > suit2          => (rank, rank),
+                    HandClass::Suited(high, low) if suit1 == suit2  => (high, low),
+                    HandClass::Offsuit(high, low) if suit1 != suit2 => (high, low),
+                    _ => continue
+                };
+
+                combos.push(Pocket::new(Card::new(combo.0, suit1), Card::new(combo.1, suit2)));
+            }
+        }
+
+        return combos;
+    }
+}
+
+impl Display for HandClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            HandClass::Pair(rank)           => write!(f, "{}{}", rank, rank),
+            HandClass::Suited(high, low)    => write!(f, "{}{}s", high, low),
+            HandClass::Offsuit(high, low)   => write!(f, "{}{}o", high, low),
+        };
+    }
+}
+
+/// Set of concrete starting hand combos, each combo has a positive weight
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Range {
+    /// Combos with the higher card first
+    combos: BTreeMap<Pocket, f64>
+}
+
+impl Range {
+    #[inline]
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Inserts combo with weight 1, returns `false` if combo is already in range
+    #[inline]
+    pub fn insert(&mut self, combo: Pocket) -> bool {
+        return self.insert_weighted(combo, 1.0);
+    }
+
+    /// Sets weight of the combo, non-positive weight removes it.
+    /// Returns `false` if combo is already in range.
+    pub fn insert_weighted(&mut self, combo: Pocket, weight: f64) -> bool {
+        if weight <= 0.0 {
+            self.combos.remove(&normalize(combo));
+            return false;
+        }
+
+        return self.combos.insert(normalize(combo), weight).is_none();
+    }
+
+    #[inline]
+    pub fn insert_class(&mut self, class: HandClass) {
+        self.insert_class_weighted(class, 1.0);
+    }
+
+    #[inline]
+    pub fn insert_class_weighted(&mut self, class: HandClass, weight: f64) {
+        for combo in class.combos() {
+            self.insert_weighted(combo, weight);
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, combo: &Pocket) -> bool {
+        return self.combos.contains_key(&normalize(*combo));
+    }
+
+    #[inline]
+    pub fn contains_class(&self, class: HandClass) -> bool {
+        return class.combos().iter().all(|combo| self.combos.contains_key(combo));
+    }
+
+    /// Weight of the combo, 0 if it is not in range
+    #[inline]
+    pub fn weight(&self, combo: &Pocket) -> f64 {
+        return self.combos.get(&normalize(*combo)).copied().unwrap_or(0.0);
+    }
+
+    /// Combos with the higher card first
+    #[inline]
+    pub fn combos(&self) -> impl Iterator<Item = &Pocket> {
+        return self.combos.keys();
+    }
+
+    #[inline]
+    pub fn weighted_combos(&self) -> impl Iterator<Item = (&Pocket, f64)> {
+        return self.combos.iter().map(|(combo, weight)| (combo, *weight));
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        return self.combos.len();
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        return self.combos.is_empty();
+    }
+
+    /// Compact notation of the range, combos with weight other than 1 get a `:weight` suffix
+    fn weighted_notation(&self) -> Vec<String> {
+        let mut weights: Vec<_> = self.combos.values().copied().collect();
+        weights.sort_unstable_by(|w1, w2| w2.total_cmp(w1));
+        weights.dedup();
+
+        return weights.into_iter()
+            .flat_map(|weight| {
+                let combos = self.combos.iter()
+                    .filter(|(_, combo_weight)| **combo_weight == weight)
+                    .map(|(combo, _)| *combo);
+                let parts = Range::from_iter(combos).notation();
+
+                return parts.into_iter().map(move |part| {
+                    if weight == 1.0 { part } else { format!("{}:{}", part, weight) }
+                });
+            })
+            .collect();
+    }
+
+    /// Compact notation of the range ignoring weights, e.g. `TT+, A5s-A2s, KQ, 76s+, JhTh`
+    fn notation(&self) -> Vec<String> {
+        const SUFFIXES: [&str; 3] = ["", "s", "o"];
+
+        let mut parts = Vec::new();
+        let mut covered = BTreeSet::new();
+
+        let pairs: Vec<_> = Rank::ALL.into_iter().rev().filter(|rank| self.contains_class(HandClass::Pair(*rank))).collect();
+        parts.extend(runs(&pairs).into_iter().map(|run| span(run, Some(Rank::Ace), |rank| HandClass::Pair(rank).to_string())));
+        covered.extend(pairs.iter().flat_map(|rank| HandClass::Pair(*rank).combos()));
+
+        // Keyed by the first class of a part, so parts are listed from the highest rank down
+        let mut unpaired = BTreeMap::new();
+        // High ranks of classes left out of kicker runs, by suffix and gap between ranks
+        let mut diagonals: BTreeMap<(usize, u8), Vec<Rank>> = BTreeMap::new();
+
+        for high in Rank::ALL.into_iter().rev() {
+            let kickers = |class: fn(Rank, Rank) -> HandClass| -> Vec<Rank> {
+                return Rank::ALL.into_iter()
+                    .rev()
+                    .filter(|low| *low < high && self.contains_class(class(high, *low)))
+                    .collect();
+            };
+            let suited = kickers(HandClass::Suited);
+            let offsuit = kickers(HandClass::Offsuit);
+            let both: Vec<_> = suited.iter().copied().filter(|low| offsuit.contains(low)).collect();
+            let top_kicker = high.lower().unwrap_or(high);
+            let groups = [
+                both.clone(),
+                suited.into_iter().filter(|low| !both.contains(low)).collect(),
+                offsuit.into_iter().filter(|low| !both.contains(low)).collect()
+            ];
+
+            for (order, kickers) in groups.into_iter().enumerate() {
+                let suffix = SUFFIXES[order];
+
+                for run in runs(&kickers) {
+                    if run.len() == 1 {
+                        diagonals.entry((order, high as u8 - run[0] as u8)).or_default().push(high);
+                    } else {
+                        let part = span(run, Some(top_kicker), |low| format!("{}{}{}", high, low, suffix));
+                        unpaired.insert((Reverse(high), order, Reverse(run[0])), part);
+                    }
+                }
+
+                for low in kickers {
+                    if suffix != "o" {
+                        covered.extend(HandClass::Suited(high, low).combos());
+                    }
+
+                    if suffix != "s" {
+                        covered.extend(HandClass::Offsuit(high, low).combos());
+                    }
+                }
+            }
+        }
+
+        // Connectors and gappers with the same gap go up together, `76s+` for connectors up to `AKs`
+        for ((order, gap), highs) in diagonals {
+            let low = |high: Rank| Rank::ALL[(high as u8 - gap) as usize];
+
+            for run in runs(&highs) {
+                let part = span(run, (gap == 1).then_some(Rank::Ace), |high| format!("{}{}{}", high, low(high), SUFFIXES[order]));
+                unpaired.insert((Reverse(run[0]), order, Reverse(low(run[0]))), part);
+            }
+        }
+
+        parts.extend(unpaired.into_values());
+
+        // Partial classes are listed combo by combo
+        parts.extend(
+            self.combos.keys()
+                .rev()
+                .filter(|combo| !covered.contains(*combo))
+                .map(|combo| format!("{}{}", combo.card1(), combo.card2()))
+        );
+
+        return parts;
+    }
+}
+
+impl Display for Range {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}", self.weighted_notation().join(", "));
+    }
+}
+
+impl FromIterator<Pocket> for Range {
+    fn from_iter<T: IntoIterator<Item = Pocket>>(iter: T) -> Self {
+        let mut range = Range::new();
+
+        for combo in iter {
+            range.insert(combo);
+        }
+
+        return range;
+    }
+}
+
+/// Puts the higher card first
+#[inline]
+fn normalize(combo: Pocket) -> Pocket {
+    if combo.card1() < combo.card2() {
+        return Pocket::new(combo.card2(), combo.card1());
+    }
+
+    return combo;
+}
+
+/// Splits ranks in descending order into runs of consecutive ranks
+fn runs(ranks: &[Rank]) -> Vec<&[Rank]> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+
+    for i in 1..=ranks.len() {
+        if i == ranks.len() || ranks[i - 1].lower() != Some(ranks[i]) {
+            runs.push(&ranks[start..i]);
+            start = i;
+        }
+    }
+
+    return runs;
+}
+
+/// Notation of a run of consecutive ranks in descending order:
+/// `XX+`/`AXs+` if it reaches the `top` rank, `XX-YY` otherwise
+fn span(run: &[Rank], top: Option<Rank>, notation: impl Fn(Rank) -> String) -> String {
+    let first = run[0];
+    let last = run[run.len() - 1];
+
+    if run.len() == 1 {
+        return notation(first);
+    }
+
+    if Some(first) == top {
+        return format!("{}+", notation(last));
+    }
+
+    return format!("{}-{}", notation(first), notation(last));
+}
@@ -0,0 +1,353 @@
+use std::fmt::Display;
+
+use crate::naive_evaluator::{card::{Card, Rank, Suit}, pocket::Pocket};
+
+use super::{HandClass, Range};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeParseError {
+    /// Range has an empty part, e.g. `AKs,,QQ`
+    EmptyToken,
+    /// Part of the range is not a hand class or a combo
+    InvalidToken(String),
+    UnknownRank { token: String, character: char },
+    /// Hand class suffix other than `s` or `o`
+    UnknownSuffix { token: String, character: char },
+    /// Pair can't be suited or offsuit, e.g. `TTs`
+    SuitedPair(String),
+    /// Combo has the same card twice, e.g. `AhAh`
+    DuplicateCard { token: String, card: Card },
+    /// Ends of a `-` range don't belong to the same family, e.g. `A2s-K6s`
+    InvalidSpan(String),
+    /// Weight after `:` is not a positive number
+    InvalidWeight(String)
+}
+
+impl Display for RangeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            RangeParseError::EmptyToken                         => write!(f, "range has an empty hand"),
+            RangeParseError::InvalidToken(token)                => write!(f, "'{}' is not a hand class or a combo", token),
+            RangeParseError::UnknownRank { token, character }   => write!(f, "unknown rank '{}' in '{}'", character, token),
+            RangeParseError::UnknownSuffix { token, character } => write!(f, "unknown suffix '{}' in '{}', expected 's' or 'o'", character, token),
+            RangeParseError::SuitedPair(token)                  => write!(f, "pair '{}' can't be suited or offsuit", token),
+            RangeParseError::DuplicateCard { token, card }      => write!(f, "card {} is used twice in '{}'", card, token),
+            RangeParseError::InvalidSpan(token)                 => write!(f, "ends of '{}' must be pairs, share the first rank or the gap between ranks, and share the suffix", token),
+            RangeParseError::InvalidWeight(token)               => write!(f, "weight of '{}' must be a positive number", token),
+        };
+    }
+}
+
+impl std::error::Error for RangeParseError {}
+
+/// Hand class as written, suffix is optional
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ClassNotation {
+    high: Rank,
+    low: Rank,
+    suffix: Option<char>
+}
+
+impl ClassNotation {
+    fn classes(&self) -> Vec<HandClass> {
+        if self.high == self.low {
+            return vec![HandClass::Pair(self.high)];
+        }
+
+        return match self.suffix {
+            Some('s')   => vec![HandClass::Suited(self.high, self.low)],
+            Some('o')   => vec![HandClass::Offsuit(self.high, self.low)],
+            _           => vec![HandClass::Suited(self.high, self.low), HandClass::Offsuit(self.high, self.low)],
+        };
+    }
+
+    #[inline]
+    fn with_ranks(&self, high: Rank, low: Rank) -> Self {
+        return Self { high, low, suffix: self.suffix };
+    }
+}
+
+impl TryFrom<&str> for Range {
+    type Error = RangeParseError;
+
+    /// Parses comma separated hands:
+    /// - `AKs`, `AKo`, `AK` - suited, offsuit or both
+    /// - `TT+` - pairs from tens up to aces
+    /// - `A2s+` - kicker goes up to one below the first rank, `A2s` to `AKs`
+    /// - `76s+` - connectors go up together, `76s` to `AKs`
+    /// - `A2s-A5s`, `77-TT` - everything in between
+    /// - `T9s-76s`, `AQs-86s` - connectors or gappers with the same gap in between
+    /// - `JhTh` - specific combo
+    /// - `AKs:0.5` - any of the above with a weight, 1 by default
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut range = Range::new();
+
+        for token in value.split(',').map(str::trim) {
+            if token.is_empty() {
+                return Err(RangeParseError::EmptyToken);
+            }
+
+            let (hands, weight) = parse_weight(token)?;
+
+            if let Some(combo) = parse_combo(hands)? {
+                range.insert_weighted(combo, weight);
+                continue;
+            }
+
+            for class in parse_classes(hands)? {
+                range.insert_class_weighted(class, weight);
+            }
+        }
+
+        return Ok(range);
+    }
+}
+
+/// Splits `AKs:0.5` into hands and weight
+fn parse_weight(token: &str) -> Result<(&str, f64), RangeParseError> {
+    let Some((hands, weight)) = token.split_once(':') else {
+        return Ok((token, 1.0));
+    };
+
+    return match weight.trim().parse::<f64>() {
+        Ok(weight) if weight > 0.0 && weight.is_finite() => Ok((hands.trim(), weight)),
+        _ => Err(RangeParseError::InvalidWeight(token.to_string()))
+    };
+}
+
+/// Parses specific combo like `JhTh`, returns `None` if token is not a combo
+fn parse_combo(token: &str) -> Result<Option<Pocket>, RangeParseError> {
+    let chars: Vec<_> = token.chars().collect();
+
+    if chars.len() != 4 || Suit::try_from(chars[1]).is_err() {
+        return Ok(None);
+    }
+
+    let card = |i: usize| -> Result<Card, RangeParseError> {
+        let rank = Rank::try_from(chars[i])
+            .map_err(|_| RangeParseError::UnknownRank { token: token.to_string(), character: chars[i] })?;
+        let suit = Suit::try_from(chars[i + 1])
+            .map_err(|_| RangeParseError::InvalidToken(token.to_string()))?;
+        return Ok(Card::new(rank, suit));
+    };
+    let card1 = card(0)?;
+    let card2 = card(2)?;
+
+    if card1 == card2 {
+        return Err(RangeParseError::DuplicateCard { token: token.to_string(), card: card1 });
+    }
+
+    return Ok(Some(Pocket::new(card1, card2)));
+}
+
+fn parse_classes(token: &str) -> Result<Vec<HandClass>, RangeParseError> {
+    let notations = if let Some(class) = token.strip_suffix('+') {
+        expand_plus(parse_class(class, token)?)
+    } else if let Some((first, last)) = token.split_once('-') {
+        expand_span(parse_class(first, token)?, parse_class(last, token)?, token)?
+    } else {
+        vec![parse_class(token, token)?]
+    };
+
+    return Ok(notations.iter().flat_map(|notation| notation.classes()).collect());
+}
+
+/// `token` - whole token for error messages
+fn parse_class(class: &str, token: &str) -> Result<ClassNotation, RangeParseError> {
+    let chars: Vec<_> = class.chars().collect();
+
+    if chars.len() != 2 && chars.len() != 3 {
+        return Err(RangeParseError::InvalidToken(token.to_string()));
+    }
+
+    let rank = |character: char| Rank::try_from(character)
+        .map_err(|_| RangeParseError::UnknownRank { token: token.to_string(), character });
+    let rank1 = rank(chars[0])?;
+    let rank2 = rank(chars[1])?;
+    let suffix = chars.get(2).copied();
+
+    if let Some(character) = suffix {
+        if character != 's' && character != 'o' {
+            return Err(RangeParseError::UnknownSuffix { token: token.to_string(), character });
+        }
+
+        if rank1 == rank2 {
+            return Err(RangeParseError::SuitedPair(token.to_string()));
+        }
+    }
+
+    return Ok(ClassNotation { high: rank1.max(rank2), low: rank1.min(rank2), suffix });
+}
+
+fn expand_plus(notation: ClassNotation) -> Vec<ClassNotation> {
+    let mut notations = vec![notation];
+    let mut last = notation;
+
+    if notation.high == notation.low || notation.high.lower() == Some(notation.low) {
+        // Pairs and connectors go up together
+        while let (Some(high), Some(low)) = (last.high.higher(), last.low.higher()) {
+            last = last.with_ranks(high, low);
+            notations.push(last);
+        }
+    } else {
+        while let Some(low) = last.low.higher().filter(|low| *low < notation.high) {
+            last = last.with_ranks(notation.high, low);
+            notations.push(last);
+        }
+    }
+
+    return notations;
+}
+
+fn expand_span(first: ClassNotation, last: ClassNotation, token: &str) -> Result<Vec<ClassNotation>, RangeParseError> {
+    let is_pairs = first.high == first.low && last.high == last.low;
+    let is_unpaired = first.suffix == last.suffix && first.high != first.low && last.high != last.low;
+    let is_kickers = is_unpaired && first.high == last.high;
+    let gap = first.high as u8 - first.low as u8;
+    let is_diagonal = is_unpaired && !is_kickers && last.high as u8 - last.low as u8 == gap;
+
+    if !is_pairs && !is_kickers && !is_diagonal {
+        return Err(RangeParseError::InvalidSpan(token.to_string()));
+    }
+
+    let (from, to) = (first.low.min(last.low), first.low.max(last.low));
+
+    return Ok(
+        Rank::ALL.into_iter()
+            .filter(|rank| (from..=to).contains(rank))
+            .map(|rank| if is_pairs {
+                first.with_ranks(rank, rank)
+            } else if is_kickers {
+                first.with_ranks(first.high, rank)
+            } else {
+                first.with_ranks(Rank::ALL[(rank as u8 + gap) as usize], rank)
+            })
+            .collect()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        naive_evaluator::{card::{Card, Rank}, pocket::Pocket},
+        range::{HandClass, Range}
+    };
+
+    use super::RangeParseError;
+
+    fn combo(value: &str) -> Pocket {
+        return Pocket::new(Card::try_from(&value[0..2]).unwrap(), Card::try_from(&value[2..4]).unwrap());
+    }
+
+    #[test]
+    fn test_classes() {
+        assert_eq!(Range::try_from("AKs").unwrap().len(), 4);
+        assert_eq!(Range::try_from("AKo").unwrap().len(), 12);
+        assert_eq!(Range::try_from("AK").unwrap().len(), 16);
+        assert_eq!(Range::try_from("KA").unwrap(), Range::try_from("AK").unwrap());
+        assert_eq!(Range::try_from("TT").unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_plus_and_span() {
+        let range = Range::try_from("TT+").unwrap();
+        assert_eq!(range.len(), 5 * 6);
+        assert!(range.contains_class(HandClass::Pair(Rank::Ace)));
+        assert!(!range.contains_class(HandClass::Pair(Rank::Nine)));
+
+        let range = Range::try_from("A2s-A5s").unwrap();
+        assert_eq!(range, Range::try_from("A5s-A2s").unwrap());
+        assert_eq!(range.len(), 4 * 4);
+        assert!(range.contains_class(HandClass::Suited(Rank::Ace, Rank::Three)));
+
+        let range = Range::try_from("K9s+").unwrap();
+        assert_eq!(range.len(), 4 * 4);
+        assert!(range.contains_class(HandClass::Suited(Rank::King, Rank::Queen)));
+
+        let range = Range::try_from("76s+").unwrap();
+        assert_eq!(range.len(), 8 * 4);
+        assert!(range.contains_class(HandClass::Suited(Rank::Jack, Rank::Ten)));
+        assert!(range.contains_class(HandClass::Suited(Rank::Ace, Rank::King)));
+
+        assert_eq!(Range::try_from("77-TT").unwrap().len(), 4 * 6);
+
+        let range = Range::try_from("T9s-76s").unwrap();
+        assert_eq!(range, Range::try_from("76s-T9s").unwrap());
+        assert_eq!(range.len(), 4 * 4);
+        assert!(range.contains_class(HandClass::Suited(Rank::Eight, Rank::Seven)));
+
+        let range = Range::try_from("AQo-86o").unwrap();
+        assert_eq!(range.len(), 7 * 12);
+        assert!(range.contains_class(HandClass::Offsuit(Rank::Jack, Rank::Nine)));
+    }
+
+    #[test]
+    fn test_combos() {
+        let range = Range::try_from("AKs, TT+, A2s-A5s, KQo, 76s+, JhTh").unwrap();
+        // AKs and JhTh are also in 76s+
+        assert_eq!(range.len(), 30 + 16 + 12 + 32);
+        assert!(range.contains(&combo("JhTh")));
+        assert!(range.contains(&combo("ThJh")));
+        assert!(!range.contains(&combo("JdTc")));
+        assert!(range.contains(&combo("KdQs")));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(Range::try_from("AKs,,QQ"), Err(RangeParseError::EmptyToken));
+        assert_eq!(Range::try_from("AXs"), Err(RangeParseError::UnknownRank { token: String::from("AXs"), character: 'X' }));
+        assert_eq!(Range::try_from("AKx"), Err(RangeParseError::UnknownSuffix { token: String::from("AKx"), character: 'x' }));
+        assert_eq!(Range::try_from("TTs"), Err(RangeParseError::SuitedPair(String::from("TTs"))));
+        assert_eq!(Range::try_from("AhAh"), Err(RangeParseError::DuplicateCard { token: String::from("AhAh"), card: Card::try_from("Ah").unwrap() }));
+        assert_eq!(Range::try_from("A2s-K6s"), Err(RangeParseError::InvalidSpan(String::from("A2s-K6s"))));
+        assert_eq!(Range::try_from("T9s-76o"), Err(RangeParseError::InvalidSpan(String::from("T9s-76o"))));
+        assert_eq!(Range::try_from("AKQJ"), Err(RangeParseError::InvalidToken(String::from("AKQJ"))));
+        assert_eq!(
+            Range::try_from("AKs, QJx").unwrap_err().to_string(),
+            "unknown suffix 'x' in 'QJx', expected 's' or 'o'"
+        );
+    }
+
+    #[test]
+    fn test_weights() {
+        let range = Range::try_from("QQ+, AKs:0.5, AhKh:0.25").unwrap();
+        assert_eq!(range.len(), 18 + 4);
+        assert_eq!(range.weight(&combo("KsKh")), 1.0);
+        assert_eq!(range.weight(&combo("AsKs")), 0.5);
+        assert_eq!(range.weight(&combo("KhAh")), 0.25);
+        assert_eq!(range.weight(&combo("AsKd")), 0.0);
+        assert_eq!(range.to_string(), "QQ+, AsKs:0.5, AdKd:0.5, AcKc:0.5, AhKh:0.25");
+
+        let range = Range::try_from("QQ+, AKs:0.5").unwrap();
+        assert_eq!(range.to_string(), "QQ+, AKs:0.5");
+        assert_eq!(Range::try_from(range.to_string().as_str()).unwrap(), range);
+
+        assert_eq!(Range::try_from("AKs:x"), Err(RangeParseError::InvalidWeight(String::from("AKs:x"))));
+        assert_eq!(Range::try_from("AKs:-1"), Err(RangeParseError::InvalidWeight(String::from("AKs:-1"))));
+    }
+
+    #[test]
+    fn test_format() {
+        let range = Range::try_from("AKs, TT+, A2s-A5s, KQo, JhTh").unwrap();
+        assert_eq!(range.to_string(), "TT+, AKs, A5s-A2s, KQo, JhTh");
+
+        assert_eq!(Range::try_from("AKs, AKo, AQs, AQo").unwrap().to_string(), "AQ+");
+        assert_eq!(Range::try_from("K9s+").unwrap().to_string(), "K9s+");
+        assert_eq!(Range::try_from("KQs").unwrap().to_string(), "KQs");
+        assert_eq!(Range::try_from("99-77, AA").unwrap().to_string(), "AA, 99-77");
+        assert_eq!(Range::try_from("AsKs, AhKh, AdKd").unwrap().to_string(), "AsKs, AhKh, AdKd");
+        assert_eq!(Range::try_from("76s+").unwrap().to_string(), "76s+");
+        assert_eq!(Range::try_from("JTs, T9s, 98s, 54o, 43o").unwrap().to_string(), "JTs-98s, 54o-43o");
+        assert_eq!(Range::try_from("AQ, KJ, QT").unwrap().to_string(), "AQ-QT");
+        assert_eq!(Range::try_from("A2s+, 76s+").unwrap().to_string(), "A2s+, KQs-76s");
+        assert_eq!(Range::new().to_string(), "");
+    }
+
+    #[test]
+    fn test_format_round_trip() {
+        for notation in ["AKs, TT+, A2s-A5s, KQo, 76s+, JhTh", "22+, A2+, K9s+, QTo-Q8o, 54s", "AhAd, KK, 72o", "76s+", "AQs-86s, T9o-54o"] {
+            let range = Range::try_from(notation).unwrap();
+            assert_eq!(Range::try_from(range.to_string().as_str()).unwrap(), range, "{}", range);
+        }
+    }
+}
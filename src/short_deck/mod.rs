@@ -0,0 +1,180 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::naive_evaluator::{
+    card::Card,
+    hand_ranking::{HandRanking, HandCategory},
+    evaluator::{evaluate_with, HandRules, LowStraight}
+};
+
+/// Short deck (6+) hold'em rules.
+/// A-6-7-8-9 is the lowest straight and flush beats full house.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShortDeckRules {
+    /// Three of a kind beats straight in most modern games, straight beats it in classic rules
+    pub trips_beat_straight: bool
+}
+
+impl ShortDeckRules {
+    #[inline]
+    pub const fn hand_rules(&self) -> HandRules {
+        return HandRules {
+            low_straight: LowStraight::ShortDeck,
+            flush_beats_full_house: true,
+            trips_beat_straight: self.trips_beat_straight
+        };
+    }
+
+    /// Position of the category in the order of hand strength
+    const fn category_order(&self, category: HandCategory) -> u8 {
+        return match category {
+            HandCategory::HighCard      => 0,
+            HandCategory::Pair          => 1,
+            HandCategory::TwoPair       => 2,
+            HandCategory::ThreeOf       => if self.trips_beat_straight { 4 } else { 3 },
+            HandCategory::Straight      => if self.trips_beat_straight { 3 } else { 4 },
+            HandCategory::FullHouse     => 5,
+            HandCategory::Flush         => 6,
+            HandCategory::FourOf        => 7,
+            HandCategory::StraightFlush => 8,
+            HandCategory::FiveOf        => 9,
+        };
+    }
+}
+
+impl Default for ShortDeckRules {
+    #[inline]
+    fn default() -> Self {
+        return Self { trips_beat_straight: true };
+    }
+}
+
+/// Hand ranking ordered by short deck rules
+#[derive(Debug, Clone, Copy)]
+pub struct ShortDeckRanking {
+    ranking: HandRanking,
+    rules: ShortDeckRules
+}
+
+impl ShortDeckRanking {
+    #[inline]
+    pub const fn ranking(&self) -> HandRanking {
+        return self.ranking;
+    }
+
+    #[inline]
+    pub const fn rules(&self) -> ShortDeckRules {
+        return self.rules;
+    }
+}
+
+impl PartialEq for ShortDeckRanking {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == Ordering::Equal;
+    }
+}
+
+impl Eq for ShortDeckRanking {}
+
+impl PartialOrd for ShortDeckRanking {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for ShortDeckRanking {
+    /// Compares by category in short deck order, then by ranks same way as [`HandRanking`].
+    /// Rankings under different rules are ordered by the rules first, so comparing them is still consistent.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let category = self.rules.category_order(self.ranking.category());
+        let other_category = other.rules.category_order(other.ranking.category());
+        return self.rules.cmp(&other.rules)
+            .then(category.cmp(&other_category))
+            .then(self.ranking.cmp(&other.ranking));
+    }
+}
+
+impl Display for ShortDeckRanking {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}", self.ranking);
+    }
+}
+
+/// Best five cards out of 5, 6 or 7 cards by short deck rules
+#[inline]
+pub fn evaluate_short_deck(cards: &[Card], rules: ShortDeckRules) -> ShortDeckRanking {
+    return ShortDeckRanking { ranking: evaluate_with(cards, rules.hand_rules()), rules };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{
+        card::Rank,
+        deck::Deck,
+        hand_ranking::{HandRanking, Flush, ThreeOf},
+        notation::parse_cards
+    };
+
+    use super::{evaluate_short_deck, ShortDeckRules};
+
+    const TRIPS_HIGH: ShortDeckRules = ShortDeckRules { trips_beat_straight: true };
+    const STRAIGHT_HIGH: ShortDeckRules = ShortDeckRules { trips_beat_straight: false };
+
+    #[test]
+    fn test_short_deck() {
+        let deck = Deck::new_short();
+        assert_eq!(deck.cards().len(), Deck::SHORT_SIZE);
+        assert!(deck.cards().iter().all(|card| card.rank() >= Rank::Six));
+    }
+
+    #[test]
+    fn test_ace_nine_straight() {
+        let ranking = evaluate_short_deck(&parse_cards("As6d7c8h9sKd").unwrap(), TRIPS_HIGH);
+        assert_eq!(ranking.ranking(), HandRanking::Straight(Rank::Nine));
+
+        let higher = evaluate_short_deck(&parse_cards("6d7c8h9sTsKd").unwrap(), TRIPS_HIGH);
+        assert!(higher > ranking);
+
+        let ranking = evaluate_short_deck(&parse_cards("Ah6h7h8h9hKd").unwrap(), TRIPS_HIGH);
+        assert_eq!(ranking.ranking(), HandRanking::StraightFlush(Rank::Nine));
+    }
+
+    #[test]
+    fn test_flush_beats_full_house() {
+        let flush = evaluate_short_deck(&parse_cards("AhKh9h7h6hKdKs").unwrap(), TRIPS_HIGH);
+        assert_eq!(flush.ranking(), HandRanking::Flush(Flush::new([Rank::Ace, Rank::King, Rank::Nine, Rank::Seven, Rank::Six])));
+
+        let full_house = evaluate_short_deck(&parse_cards("AsAdAcKdKs").unwrap(), TRIPS_HIGH);
+        assert!(flush > full_house);
+    }
+
+    #[test]
+    fn test_trips_and_straight_order() {
+        let cards = parse_cards("9s9d9c8h7sTdJc").unwrap();
+
+        let trips = evaluate_short_deck(&cards, TRIPS_HIGH);
+        assert_eq!(trips.ranking(), HandRanking::ThreeOf(ThreeOf::new(Rank::Nine, [Rank::Jack, Rank::Ten])));
+
+        let straight = evaluate_short_deck(&cards, STRAIGHT_HIGH);
+        assert_eq!(straight.ranking(), HandRanking::Straight(Rank::Jack));
+
+        let other_straight = evaluate_short_deck(&parse_cards("AsKdQcJhTs").unwrap(), TRIPS_HIGH);
+        assert!(trips > other_straight);
+
+        let other_straight = evaluate_short_deck(&parse_cards("AsKdQcJhTs").unwrap(), STRAIGHT_HIGH);
+        let other_trips = evaluate_short_deck(&parse_cards("6s6d6cJhTs").unwrap(), STRAIGHT_HIGH);
+        assert!(other_trips < other_straight);
+    }
+
+    #[test]
+    fn test_order_under_different_rules() {
+        let trips = evaluate_short_deck(&parse_cards("6s6d6cJhTs").unwrap(), TRIPS_HIGH);
+        let straight = evaluate_short_deck(&parse_cards("AsKdQcJhTs").unwrap(), STRAIGHT_HIGH);
+
+        assert_eq!(trips.cmp(&straight), straight.cmp(&trips).reverse());
+        assert_ne!(trips, straight);
+        assert_ne!(trips, evaluate_short_deck(&parse_cards("6s6d6cJhTs").unwrap(), STRAIGHT_HIGH));
+    }
+}
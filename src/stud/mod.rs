@@ -0,0 +1,301 @@
+use std::fmt::Display;
+
+use crate::naive_evaluator::{
+    card::Card,
+    deck::Deck,
+    hand_ranking::PartialHandRanking,
+    evaluator::evaluate_partial
+};
+
+/// Betting round of seven-card stud, named after the number of cards each player has
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StudStreet {
+    /// Two down cards and one upcard
+    Third,
+    Fourth,
+    Fifth,
+    Sixth,
+    /// Last card is dealt down
+    Seventh
+}
+
+impl StudStreet {
+    pub const ALL: [StudStreet; 5] = [StudStreet::Third, StudStreet::Fourth, StudStreet::Fifth, StudStreet::Sixth, StudStreet::Seventh];
+
+    #[inline]
+    pub fn next(self) -> Option<StudStreet> {
+        return StudStreet::ALL.get(self as usize + 1).copied();
+    }
+
+    /// Number of (down, up) cards each player gets on the street
+    #[inline]
+    pub const fn dealt_cards(self) -> (usize, usize) {
+        return match self {
+            StudStreet::Third   => (2, 1),
+            StudStreet::Seventh => (1, 0),
+            _                   => (0, 1)
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StudError {
+    /// Stud needs at least two players
+    TooFewPlayers,
+    /// Deck doesn't have enough cards to deal the street to every player
+    NotEnoughCards,
+    /// Seventh street is already dealt
+    HandComplete
+}
+
+impl Display for StudError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            StudError::TooFewPlayers    => write!(f, "at least two players are required"),
+            StudError::NotEnoughCards   => write!(f, "not enough cards left to deal the street"),
+            StudError::HandComplete     => write!(f, "all streets are already dealt"),
+        };
+    }
+}
+
+impl std::error::Error for StudError {}
+
+/// Cards of a single stud player
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StudHand {
+    down: Vec<Card>,
+    up: Vec<Card>
+}
+
+impl StudHand {
+    /// Cards seen only by the player, in the order they were dealt
+    #[inline]
+    pub fn down_cards(&self) -> &[Card] {
+        return &self.down;
+    }
+
+    /// Cards seen by everybody, in the order they were dealt
+    #[inline]
+    pub fn up_cards(&self) -> &[Card] {
+        return &self.up;
+    }
+
+    /// Down and up cards
+    #[inline]
+    pub fn cards(&self) -> Vec<Card> {
+        return [&self.down[..], &self.up[..]].concat();
+    }
+
+    /// Ranking of the upcards, `None` before third street
+    #[inline]
+    pub fn showing(&self) -> Option<PartialHandRanking> {
+        if self.up.is_empty() {
+            return None;
+        }
+
+        return Some(evaluate_partial(&self.up));
+    }
+}
+
+/// Deals seven-card stud and decides who acts first on each street
+#[derive(Debug, Clone)]
+pub struct StudDealer {
+    deck: Deck,
+    hands: Vec<StudHand>,
+    folded: Vec<bool>,
+    street: Option<StudStreet>
+}
+
+impl StudDealer {
+    /// `players` - number of players, seat 0 is the first one left of the dealer
+    pub fn new(deck: Deck, players: usize) -> Result<Self, StudError> {
+        if players < 2 {
+            return Err(StudError::TooFewPlayers);
+        }
+
+        return Ok(Self { deck, hands: vec![StudHand::default(); players], folded: vec![false; players], street: None });
+    }
+
+    #[inline]
+    pub fn deck(&self) -> &Deck {
+        return &self.deck;
+    }
+
+    #[inline]
+    pub fn hands(&self) -> &[StudHand] {
+        return &self.hands;
+    }
+
+    #[inline]
+    pub fn hand(&self, seat: usize) -> &StudHand {
+        return &self.hands[seat];
+    }
+
+    /// Last dealt street, `None` before third street
+    #[inline]
+    pub fn street(&self) -> Option<StudStreet> {
+        return self.street;
+    }
+
+    /// Folded players don't get any more cards and never act first
+    #[inline]
+    pub fn fold(&mut self, seat: usize) {
+        self.folded[seat] = true;
+    }
+
+    #[inline]
+    pub fn is_folded(&self, seat: usize) -> bool {
+        return self.folded[seat];
+    }
+
+    /// Deals the next street to every player still in the hand, one card at a time in seat order.
+    /// Deals nothing if the deck can't complete the street.
+    pub fn deal_next_street(&mut self) -> Result<StudStreet, StudError> {
+        let street = match self.street {
+            None            => StudStreet::Third,
+            Some(street)    => street.next().ok_or(StudError::HandComplete)?
+        };
+
+        let (down, up) = street.dealt_cards();
+        let active: Vec<_> = (0..self.hands.len()).filter(|seat| !self.folded[*seat]).collect();
+
+        if self.deck.cards().len() < (down + up) * active.len() {
+            return Err(StudError::NotEnoughCards);
+        }
+
+        for round in 0..down + up {
+            for seat in &active {
+                let card = self.deck.draw_card().unwrap();
+                let hand = &mut self.hands[*seat];
+
+                if round < down {
+                    hand.down.push(card);
+                } else {
+                    hand.up.push(card);
+                }
+            }
+        }
+
+        self.street = Some(street);
+        return Ok(street);
+    }
+
+    /// Seat with the lowest third street upcard, ties are broken by suit with Clubs being the lowest.
+    /// Aces are high. `None` before third street.
+    pub fn bring_in(&self) -> Option<usize> {
+        return self.active_seats()
+            .filter_map(|seat| self.hands[seat].up.first().map(|card| ((card.rank(), card.suit()), seat)))
+            .min()
+            .map(|(_, seat)| seat);
+    }
+
+    /// Bring-in acts first on third street, later the best showing hand does.
+    /// Ties go to the seat closest to the dealer's left. `None` before third street.
+    pub fn first_to_act(&self) -> Option<usize> {
+        return match self.street? {
+            StudStreet::Third => self.bring_in(),
+            _ => {
+                let showing = self.active_seats().filter_map(|seat| self.hands[seat].showing().map(|showing| (showing, seat)));
+                let best = showing.clone().map(|(showing, _)| showing).max()?;
+                showing.filter(|(other, _)| *other == best).map(|(_, seat)| seat).next()
+            }
+        };
+    }
+
+    #[inline]
+    fn active_seats(&self) -> impl Iterator<Item = usize> + Clone + '_ {
+        return (0..self.hands.len()).filter(|seat| !self.folded[*seat]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{deck::Deck, fixtures::{card, deck}, hand_ranking::HandCategory};
+
+    use super::{StudDealer, StudError, StudStreet};
+
+    #[test]
+    fn test_deals_all_streets() {
+        let mut dealer = StudDealer::new(Deck::new(), 4).unwrap();
+        assert_eq!(dealer.street(), None);
+        assert_eq!(dealer.first_to_act(), None);
+
+        for street in StudStreet::ALL {
+            assert_eq!(dealer.deal_next_street(), Ok(street));
+        }
+
+        for hand in dealer.hands() {
+            assert_eq!(hand.down_cards().len(), 3);
+            assert_eq!(hand.up_cards().len(), 4);
+        }
+
+        assert_eq!(dealer.deck().cards().len(), Deck::SIZE - 4 * 7);
+        assert_eq!(dealer.deal_next_street(), Err(StudError::HandComplete));
+        assert_eq!(StudDealer::new(Deck::new(), 1).unwrap_err(), StudError::TooFewPlayers);
+    }
+
+    #[test]
+    fn test_third_street_order() {
+        // First down cards, second down cards, then upcards
+        let mut dealer = StudDealer::new(deck("AsKs QsJs TsAd KdQd"), 2).unwrap();
+        dealer.deal_next_street().unwrap();
+
+        assert_eq!(dealer.hand(0).down_cards(), &[card("As"), card("Qs")]);
+        assert_eq!(dealer.hand(1).down_cards(), &[card("Ks"), card("Js")]);
+        assert_eq!(dealer.hand(0).up_cards(), &[card("Ts")]);
+        assert_eq!(dealer.hand(1).up_cards(), &[card("Ad")]);
+    }
+
+    #[test]
+    fn test_bring_in() {
+        // Upcards 9h, 2d, 2c
+        let mut dealer = StudDealer::new(deck("AsKsQs JsTs8s 9h2d2c"), 3).unwrap();
+        dealer.deal_next_street().unwrap();
+        assert_eq!(dealer.bring_in(), Some(2));
+        assert_eq!(dealer.first_to_act(), Some(2));
+
+        // Ace is high
+        let mut dealer = StudDealer::new(deck("AsKsQs JsTs8s Ah3d9c"), 3).unwrap();
+        dealer.deal_next_street().unwrap();
+        assert_eq!(dealer.bring_in(), Some(1));
+    }
+
+    #[test]
+    fn test_best_showing_hand_acts_first() {
+        // Upcards Ah 9d 2c, then 5h 9s 2s
+        let mut dealer = StudDealer::new(deck("AsKsQs JsTs8s Ah9d2c 5h9s2s 3c4c5c"), 3).unwrap();
+        dealer.deal_next_street().unwrap();
+        dealer.deal_next_street().unwrap();
+
+        assert_eq!(dealer.hand(1).showing().unwrap().category(), HandCategory::Pair);
+        assert_eq!(dealer.first_to_act(), Some(1));
+
+        // Folded player gets no more cards and never acts first
+        dealer.fold(1);
+        dealer.deal_next_street().unwrap();
+        assert_eq!(dealer.hand(1).up_cards().len(), 2);
+        assert_eq!(dealer.hand(2).up_cards().len(), 3);
+        assert_eq!(dealer.first_to_act(), Some(2));
+    }
+
+    #[test]
+    fn test_showing_tie_goes_to_earliest_seat() {
+        let mut dealer = StudDealer::new(deck("2s3s4s 5s6s7s 2hAhAd 3hKdKh"), 3).unwrap();
+        dealer.deal_next_street().unwrap();
+        dealer.deal_next_street().unwrap();
+        assert_eq!(dealer.first_to_act(), Some(1));
+    }
+
+    #[test]
+    fn test_not_enough_cards() {
+        let mut dealer = StudDealer::new(Deck::new(), 8).unwrap();
+
+        for _ in 0..4 {
+            dealer.deal_next_street().unwrap();
+        }
+
+        assert_eq!(dealer.deal_next_street(), Err(StudError::NotEnoughCards));
+        assert_eq!(dealer.street(), Some(StudStreet::Sixth));
+        assert_eq!(dealer.deck().cards().len(), Deck::SIZE - 8 * 6);
+    }
+}
@@ -0,0 +1,482 @@
+use std::fmt::Display;
+
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::naive_evaluator::{
+    card::{Card, ParseError, Rank, Suit},
+    deck::Deck,
+    hand_ranking::{HandRanking, FourOf, FullHouse, Flush, ThreeOf, TwoPair, Pair},
+    evaluator::{evaluate, assert_hand_size, CARDS_IN_COMBO}
+};
+
+pub const MAX_JOKERS: usize = 2;
+
+/// Standard card or a joker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PlayingCard {
+    Standard(Card),
+    Joker
+}
+
+impl From<Card> for PlayingCard {
+    #[inline]
+    fn from(value: Card) -> Self {
+        return PlayingCard::Standard(value);
+    }
+}
+
+impl TryFrom<&str> for PlayingCard {
+    type Error = ParseError;
+
+    /// Standard card like `Ah` or `Jk` for a joker, in any case
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.eq_ignore_ascii_case("jk") {
+            return Ok(PlayingCard::Joker);
+        }
+
+        return Card::try_from(value).map(PlayingCard::Standard);
+    }
+}
+
+impl Display for PlayingCard {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            PlayingCard::Standard(card) => write!(f, "{}", card),
+            PlayingCard::Joker          => write!(f, "Jk"),
+        };
+    }
+}
+
+/// Standard deck with jokers
+pub type JokerDeck = Deck<PlayingCard>;
+
+impl JokerDeck {
+    /// `jokers` - up to two jokers
+    pub fn with_jokers(jokers: usize) -> Self {
+        assert!(jokers <= MAX_JOKERS, "expected at most {} jokers, got {}", MAX_JOKERS, jokers);
+
+        let mut cards: Vec<_> = Deck::new().cards().iter().map(|card| PlayingCard::Standard(*card)).collect();
+        cards.extend([PlayingCard::Joker; MAX_JOKERS].into_iter().take(jokers));
+        return Self::from_cards(cards);
+    }
+
+    /// Deck with jokers shuffled with a generator seeded by `seed`, same seed always gives the same order
+    #[inline]
+    pub fn seeded_with_jokers(jokers: usize, seed: u64) -> Self {
+        return Self::with_jokers(jokers).shuffled_with(&mut StdRng::seed_from_u64(seed));
+    }
+}
+
+/// How jokers play
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JokerRule {
+    /// Joker is any card
+    Wild,
+    /// Joker only completes straights and flushes, otherwise it is an Ace
+    Bug
+}
+
+/// Which cards are wild
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WildRules {
+    pub jokers: JokerRule,
+    /// All cards of this rank are fully wild, e.g. deuces wild
+    pub wild_rank: Option<Rank>
+}
+
+impl WildRules {
+    pub const JOKERS_WILD: WildRules = WildRules { jokers: JokerRule::Wild, wild_rank: None };
+    pub const BUG: WildRules = WildRules { jokers: JokerRule::Bug, wild_rank: None };
+    pub const DEUCES_WILD: WildRules = WildRules { jokers: JokerRule::Wild, wild_rank: Some(Rank::Two) };
+}
+
+impl Default for WildRules {
+    #[inline]
+    fn default() -> Self {
+        return Self::JOKERS_WILD;
+    }
+}
+
+/// Best five cards out of 5 to 7 cards, where every wild card becomes whatever card makes the best hand.
+/// Wild cards can duplicate natural cards, so five of a kind is possible.
+pub fn evaluate_wild(cards: &[PlayingCard], rules: WildRules) -> HandRanking {
+    assert_hand_size(cards.len());
+
+    let mut naturals = WildHand::default();
+    let mut bugs = 0;
+
+    for card in cards {
+        match (card, rules.jokers) {
+            (PlayingCard::Joker, JokerRule::Wild)                                   => naturals.wilds += 1,
+            (PlayingCard::Joker, JokerRule::Bug)                                    => bugs += 1,
+            (PlayingCard::Standard(card), _) if Some(card.rank()) == rules.wild_rank => naturals.wilds += 1,
+            (PlayingCard::Standard(card), _)                                        => naturals.insert(card.rank(), Some(card.suit())),
+        }
+    }
+
+    if naturals.wilds == 0 && bugs == 0 {
+        let cards: Vec<_> = cards.iter().filter_map(|card| match card { PlayingCard::Standard(card) => Some(*card), _ => None }).collect();
+        return evaluate(&cards);
+    }
+
+    // Bugs playing as Aces make any hand, bugs playing as wild cards only make straights and flushes
+    return (0..=bugs)
+        .filter_map(|wild_bugs| {
+            let mut hand = naturals;
+            hand.wilds += wild_bugs;
+
+            for _ in wild_bugs..bugs {
+                hand.insert(Rank::Ace, None);
+            }
+
+            if wild_bugs == 0 {
+                return Some(hand.best());
+            }
+
+            return hand.straight_flush().or_else(|| hand.flush()).or_else(|| hand.straight());
+        })
+        .max()
+        .unwrap();
+}
+
+/// Natural cards and number of wild cards
+#[derive(Debug, Clone, Copy, Default)]
+struct WildHand {
+    /// Number of natural cards of each rank
+    rank_counts: [usize; 13],
+    /// Rank masks of natural cards of each suit
+    suits: [u16; 4],
+    wilds: usize
+}
+
+impl WildHand {
+    /// `suit` - `None` for an Ace played by a bug
+    #[inline]
+    fn insert(&mut self, rank: Rank, suit: Option<Suit>) {
+        self.rank_counts[rank as usize] += 1;
+
+        if let Some(suit) = suit {
+            self.suits[suit as usize] |= 1 << rank as u16;
+        }
+    }
+
+    #[inline]
+    fn count(&self, rank: Rank) -> usize {
+        return self.rank_counts[rank as usize];
+    }
+
+    /// Wild cards needed to make `count` cards of the rank
+    #[inline]
+    fn missing(&self, rank: Rank, count: usize) -> usize {
+        return count.saturating_sub(self.count(rank));
+    }
+
+    /// Ranks of natural cards from high to low, repeated for each card
+    fn natural_ranks<'a>(&'a self, except: &'a [Rank]) -> impl Iterator<Item = Rank> + 'a {
+        return Rank::ALL.into_iter()
+            .rev()
+            .filter(move |rank| !except.contains(rank))
+            .flat_map(|rank| std::iter::repeat_n(rank, self.count(rank)));
+    }
+
+    /// Best ranking, wild cards are used up to make it
+    fn best(&self) -> HandRanking {
+        return self.five_of()
+            .or_else(|| self.straight_flush())
+            .or_else(|| self.four_of())
+            .or_else(|| self.full_house())
+            .or_else(|| self.flush())
+            .or_else(|| self.straight())
+            .or_else(|| self.three_of())
+            .unwrap_or_else(|| self.pair_or_high_card());
+    }
+
+    fn five_of(&self) -> Option<HandRanking> {
+        return Rank::ALL.into_iter()
+            .rev()
+            .find(|rank| self.missing(*rank, 5) <= self.wilds)
+            .map(HandRanking::FiveOf);
+    }
+
+    fn straight_flush(&self) -> Option<HandRanking> {
+        return Suit::ALL.into_iter()
+            .filter_map(|suit| straight_high(|rank| self.suits[suit as usize] & 1 << rank as u16 != 0, self.wilds))
+            .max()
+            .map(HandRanking::StraightFlush);
+    }
+
+    /// Called when there is no five of a kind, so no wild card is left for the kicker
+    fn four_of(&self) -> Option<HandRanking> {
+        let rank = Rank::ALL.into_iter().rev().find(|rank| self.missing(*rank, 4) <= self.wilds)?;
+        let kicker = self.natural_ranks(&[rank]).next()?;
+        return Some(HandRanking::FourOf(FourOf::new(rank, kicker)));
+    }
+
+    fn full_house(&self) -> Option<HandRanking> {
+        for three_of in Rank::ALL.into_iter().rev() {
+            for pair_of in Rank::ALL.into_iter().rev().filter(|rank| *rank != three_of) {
+                if self.missing(three_of, 3) + self.missing(pair_of, 2) <= self.wilds {
+                    return Some(HandRanking::FullHouse(FullHouse::new(pair_of, three_of)));
+                }
+            }
+        }
+
+        return None;
+    }
+
+    /// Wild cards become the highest ranks missing in the suit
+    fn flush(&self) -> Option<HandRanking> {
+        return Suit::ALL.into_iter()
+            .filter_map(|suit| {
+                let mut wilds = self.wilds;
+                let mut ranks = Rank::ALL.into_iter().rev().filter(|rank| {
+                    if self.suits[suit as usize] & 1 << *rank as u16 != 0 {
+                        return true;
+                    }
+
+                    if wilds > 0 {
+                        wilds -= 1;
+                        return true;
+                    }
+
+                    return false;
+                });
+
+                let flush: Vec<_> = ranks.by_ref().take(CARDS_IN_COMBO).collect();
+                return flush.try_into().ok().map(|flush| HandRanking::Flush(Flush::new(flush)));
+            })
+            .max();
+    }
+
+    fn straight(&self) -> Option<HandRanking> {
+        return straight_high(|rank| self.count(rank) > 0, self.wilds).map(HandRanking::Straight);
+    }
+
+    /// Called when there are no quads, so no wild card is left for kickers
+    fn three_of(&self) -> Option<HandRanking> {
+        let rank = Rank::ALL.into_iter().rev().find(|rank| self.missing(*rank, 3) <= self.wilds)?;
+        let kickers: Vec<_> = self.natural_ranks(&[rank]).take(2).collect();
+        return Some(HandRanking::ThreeOf(ThreeOf::new(rank, kickers.try_into().ok()?)));
+    }
+
+    /// Called when there are no trips, so there is either one wild card and no pairs or no wild cards
+    fn pair_or_high_card(&self) -> HandRanking {
+        let ranks: Vec<_> = self.natural_ranks(&[]).collect();
+
+        if self.wilds > 0 {
+            return HandRanking::Pair(Pair::new(ranks[0], ranks[1..4].try_into().unwrap()));
+        }
+
+        let pairs: Vec<_> = Rank::ALL.into_iter().rev().filter(|rank| self.count(*rank) == 2).take(2).collect();
+
+        return match pairs[..] {
+            [] => HandRanking::HighCard(ranks[..5].try_into().unwrap()),
+            [pair] => {
+                let kickers: Vec<_> = self.natural_ranks(&[pair]).take(3).collect();
+                HandRanking::Pair(Pair::new(pair, kickers.try_into().unwrap()))
+            },
+            _ => {
+                let kicker = self.natural_ranks(&pairs).next().unwrap();
+                HandRanking::TwoPair(TwoPair::new([pairs[0], pairs[1]], kicker))
+            }
+        };
+    }
+}
+
+/// Highest card of the best straight, `present` tells whether there is a natural card of the rank
+fn straight_high(present: impl Fn(Rank) -> bool, wilds: usize) -> Option<Rank> {
+    return Rank::ALL.into_iter()
+        .rev()
+        .take_while(|high| *high >= Rank::Five)
+        .find(|high| {
+            let missing = (0..CARDS_IN_COMBO)
+                .map(|i| if *high == Rank::Five && i == CARDS_IN_COMBO - 1 { Rank::Ace } else { Rank::ALL[*high as usize - i] })
+                .filter(|rank| !present(*rank))
+                .count();
+            return missing <= wilds;
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naive_evaluator::{
+        card::{Card, Rank},
+        deck::Deck,
+        hand_ranking::{HandCategory, HandRanking, FourOf, FullHouse, Flush, ThreeOf, Pair},
+        evaluator::evaluate
+    };
+
+    use super::{evaluate_wild, JokerDeck, JokerRule, PlayingCard, WildRules};
+
+    fn cards(value: &str) -> Vec<PlayingCard> {
+        return (0..value.len()).step_by(2).map(|i| PlayingCard::try_from(&value[i..i + 2]).unwrap()).collect();
+    }
+
+    #[test]
+    fn test_joker_deck() {
+        let deck = JokerDeck::with_jokers(2).shuffled();
+        assert_eq!(deck.cards().len(), 54);
+        assert_eq!(deck.cards().iter().filter(|card| **card == PlayingCard::Joker).count(), 2);
+        assert_eq!(PlayingCard::Joker.to_string(), "Jk");
+        assert_eq!(PlayingCard::try_from("jk"), Ok(PlayingCard::Joker));
+        assert_eq!(PlayingCard::try_from("JK"), Ok(PlayingCard::Joker));
+        assert_eq!(JokerDeck::seeded_with_jokers(1, 3).cards(), JokerDeck::seeded_with_jokers(1, 3).cards());
+        assert_ne!(JokerDeck::seeded_with_jokers(1, 3).cards(), JokerDeck::with_jokers(1).cards());
+    }
+
+    #[test]
+    fn test_five_of_a_kind() {
+        let ranking = evaluate_wild(&cards("AsAhAdAcJk"), WildRules::JOKERS_WILD);
+        assert_eq!(ranking, HandRanking::FiveOf(Rank::Ace));
+        assert_eq!(ranking.category(), HandCategory::FiveOf);
+        assert!(ranking > HandRanking::StraightFlush(Rank::Ace));
+
+        assert_eq!(evaluate_wild(&cards("7s7h2d2cJk"), WildRules::DEUCES_WILD), HandRanking::FiveOf(Rank::Seven));
+    }
+
+    #[test]
+    fn test_joker_completes_best_hand() {
+        let wild = WildRules::JOKERS_WILD;
+        assert_eq!(evaluate_wild(&cards("KsQsJsTsJk"), wild), HandRanking::StraightFlush(Rank::Ace));
+        assert_eq!(evaluate_wild(&cards("As2s3s5sJk"), wild), HandRanking::StraightFlush(Rank::Five));
+        assert_eq!(evaluate_wild(&cards("KsKhKd7c2dJk"), wild), HandRanking::FourOf(FourOf::new(Rank::King, Rank::Seven)));
+        assert_eq!(evaluate_wild(&cards("KsKh7d7c2dJk"), wild), HandRanking::FullHouse(FullHouse::new(Rank::Seven, Rank::King)));
+        assert_eq!(
+            evaluate_wild(&cards("Ks9s7s2s2dJk"), wild),
+            HandRanking::Flush(Flush::new([Rank::Ace, Rank::King, Rank::Nine, Rank::Seven, Rank::Two]))
+        );
+        assert_eq!(evaluate_wild(&cards("9sTh7d6c2dJk"), wild), HandRanking::Straight(Rank::Ten));
+        assert_eq!(
+            evaluate_wild(&cards("KsKh7d6c2dJk"), wild),
+            HandRanking::ThreeOf(ThreeOf::new(Rank::King, [Rank::Seven, Rank::Six]))
+        );
+        assert_eq!(
+            evaluate_wild(&cards("Ks9h7d4c2dJk"), wild),
+            HandRanking::Pair(Pair::new(Rank::King, [Rank::Nine, Rank::Seven, Rank::Four]))
+        );
+    }
+
+    #[test]
+    fn test_deuces_wild() {
+        let rules = WildRules::DEUCES_WILD;
+        assert_eq!(evaluate_wild(&cards("2s2h2d2cAs"), rules), HandRanking::FiveOf(Rank::Ace));
+        assert_eq!(evaluate_wild(&cards("2s2h9dTc3s"), rules), HandRanking::ThreeOf(ThreeOf::new(Rank::Ten, [Rank::Nine, Rank::Three])));
+        assert_eq!(evaluate_wild(&cards("2s2h2dTc3s"), rules), HandRanking::FourOf(FourOf::new(Rank::Ten, Rank::Three)));
+        assert_eq!(evaluate_wild(&cards("2s2hKsTsQs"), rules), HandRanking::StraightFlush(Rank::Ace));
+
+        // No wild cards in the hand
+        assert_eq!(
+            evaluate_wild(&cards("AsKhQdJc9c"), rules),
+            evaluate(&["As", "Kh", "Qd", "Jc", "9c"].map(|card| Card::try_from(card).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_bug() {
+        let bug = WildRules::BUG;
+        assert_eq!(evaluate_wild(&cards("KsQsJsTsJk"), bug), HandRanking::StraightFlush(Rank::Ace));
+        assert_eq!(evaluate_wild(&cards("9sTh7d6cJk"), bug), HandRanking::Straight(Rank::Ten));
+        assert_eq!(
+            evaluate_wild(&cards("Ks9s7s3sJk"), bug),
+            HandRanking::Flush(Flush::new([Rank::Ace, Rank::King, Rank::Nine, Rank::Seven, Rank::Three]))
+        );
+
+        // Otherwise the bug is an Ace
+        assert_eq!(evaluate_wild(&cards("KsKh7d6cJk"), bug), HandRanking::Pair(Pair::new(Rank::King, [Rank::Ace, Rank::Seven, Rank::Six])));
+        assert_eq!(evaluate_wild(&cards("AsAh7d6cJk"), bug), HandRanking::ThreeOf(ThreeOf::new(Rank::Ace, [Rank::Seven, Rank::Six])));
+        assert_eq!(evaluate_wild(&cards("AsAhAdAcJk"), bug), HandRanking::FiveOf(Rank::Ace));
+
+        // Deuces stay fully wild next to the bug
+        let bug_and_deuces = WildRules { jokers: JokerRule::Bug, wild_rank: Some(Rank::Two) };
+        assert_eq!(
+            evaluate_wild(&cards("KsKh2c7dJk"), bug_and_deuces),
+            HandRanking::ThreeOf(ThreeOf::new(Rank::King, [Rank::Ace, Rank::Seven]))
+        );
+        assert_eq!(evaluate_wild(&cards("9h8h2s5cJk"), bug_and_deuces), HandRanking::Straight(Rank::Nine));
+        assert_eq!(evaluate_wild(&cards("AsAh2d7cJk"), bug_and_deuces), HandRanking::FourOf(FourOf::new(Rank::Ace, Rank::Seven)));
+    }
+
+    /// Best ranking over every way to replace wild cards with distinct cards missing from the hand.
+    /// Bugs become Aces or complete straights and flushes, five of a kind is checked separately.
+    fn brute_force(hand: &[PlayingCard], rules: WildRules) -> HandRanking {
+        let naturals: Vec<_> = hand.iter()
+            .filter_map(|card| match card { PlayingCard::Standard(card) => Some(*card), _ => None })
+            .filter(|card| Some(card.rank()) != rules.wild_rank)
+            .collect();
+        let jokers = hand.iter().filter(|card| **card == PlayingCard::Joker).count();
+        let (wilds, bugs) = match rules.jokers {
+            JokerRule::Wild => (hand.len() - naturals.len(), 0),
+            JokerRule::Bug  => (hand.len() - naturals.len() - jokers, jokers)
+        };
+
+        let count = |rank: Rank| naturals.iter().filter(|card| card.rank() == rank).count();
+        let five_of = Rank::ALL.into_iter()
+            .rev()
+            .find(|rank| count(*rank) + wilds + if *rank == Rank::Ace { bugs } else { 0 } >= 5)
+            .map(HandRanking::FiveOf);
+
+        let mut best = None;
+        substitute(&mut naturals.clone(), wilds, bugs, false, &mut best);
+        return best.max(five_of).unwrap();
+    }
+
+    /// `bug_played_wild` - some bug is not an Ace, so only straights and flushes count
+    fn substitute(cards: &mut Vec<Card>, wilds: usize, bugs: usize, bug_played_wild: bool, best: &mut Option<HandRanking>) {
+        if wilds == 0 && bugs == 0 {
+            let ranking = evaluate(cards);
+            let completed = matches!(ranking.category(), HandCategory::Straight | HandCategory::Flush | HandCategory::StraightFlush);
+
+            if !bug_played_wild || completed {
+                *best = (*best).max(Some(ranking));
+            }
+
+            return;
+        }
+
+        for card in Deck::without(cards).cards().iter().copied() {
+            cards.push(card);
+
+            if wilds > 0 {
+                substitute(cards, wilds - 1, bugs, bug_played_wild, best);
+            } else {
+                substitute(cards, 0, bugs - 1, bug_played_wild || card.rank() != Rank::Ace, best);
+            }
+
+            cards.pop();
+        }
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let bug_and_deuces = WildRules { jokers: JokerRule::Bug, wild_rank: Some(Rank::Two) };
+        let fixed = [
+            "8s8h8d8cAhJk", "KsKh7d7cJk", "9sTh7d6c2dJk", "As2h3d9cJk", "AsKsQs2dJk", "3c3d3h8sJk", "Ah5h6h7h9c8dJk", "QsJdTc8h2hJk",
+            "KsKhKd7cJkJk", "As9s4sJkJk", "8h6h5c4dJkJk", "AsAhJk2dJk", "KsQsJdJkJk", "7s7h4c4dJkJk",
+            "KsQsJs2dJk", "AsAhAd2cJk", "9h8h6h2sJk3c", "5s4d3cAh2hJk", "KsKh2c7dJk"
+        ].map(cards);
+        let random = (0..400).map(|i| {
+            let mut deck = JokerDeck::seeded_with_jokers(2, i);
+            return (0..5 + i % 3).map(|_| deck.draw_card().unwrap()).collect::<Vec<_>>();
+        });
+
+        for hand in fixed.into_iter().chain(random) {
+            for rules in [WildRules::JOKERS_WILD, WildRules::BUG, WildRules::DEUCES_WILD, bug_and_deuces] {
+                let wilds = hand.iter()
+                    .filter(|card| match card {
+                        PlayingCard::Joker          => true,
+                        PlayingCard::Standard(card) => Some(card.rank()) == rules.wild_rank
+                    })
+                    .count();
+
+                // Brute force over three or more wild cards is too slow
+                if wilds == 0 || wilds > 2 {
+                    continue;
+                }
+
+                assert_eq!(evaluate_wild(&hand, rules), brute_force(&hand, rules), "{:?} {:?}", hand, rules);
+            }
+        }
+    }
+}